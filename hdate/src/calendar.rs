@@ -0,0 +1,653 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::Peekable;
+
+use chrono::NaiveDate;
+
+use crate::holidays::{self, FastOpinions, YOM_KIPPUR_KATAN_OMITTED_MONTHS};
+use crate::locale::Locale;
+use crate::{sedra, DafYomiEvent, Event, Flags, Hdate, HebrewMonth, Location};
+
+/// All days of the week, as a bitmask where bit 0 represents Sunday.
+const ALL_WEEK_DAYS: u8 = 0b111_1111;
+
+/// An invalid option of a [`Calendar`] query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CalendarError {
+    /// A day of the week isn't between 0 for Sunday and 6 for Saturday.
+    BadWeekDayArgument(u8),
+    /// A Gregorian date comes before the creation of the world in the Hebrew calendar.
+    BeforeEpochError(NaiveDate),
+}
+
+/// A query over the calendar events, in the spirit of hebcal's `HebrewCalendar.calendar(options)`.
+///
+/// The options compose: an event is returned only if it satisfies all of them. A query created
+/// by [`Calendar::new`] without a range returns the events of the current Hebrew year, which
+/// requires the clock of the `std` feature; without it, a query is created by
+/// [`Calendar::for_year`] or [`Calendar::starting`].
+///
+/// Events are generated lazily by [`Calendar::iter`], one Hebrew year at a time, so a query
+/// may span any number of years, or have no end at all.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{Calendar, Flags, Hdate, HebrewMonth};
+///
+/// let events = Calendar::for_year(5785)
+///     .israel(true)
+///     .exclude(Flags::RoshChodesh | Flags::MinorFast)
+///     .events();
+/// assert_eq!(events[0].description, "Rosh Hashana 5785");
+/// assert!(events.iter().all(|event| event.observed_in_israel()));
+/// assert!(!events
+///     .iter()
+///     .any(|event| event.mask.intersects(Flags::RoshChodesh | Flags::MinorFast)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Calendar {
+    start: Option<Hdate>,
+    end: Option<Hdate>,
+    israel: bool,
+    include: Option<Flags>,
+    exclude: Flags,
+    week_days: u8,
+    location: Option<Location>,
+    fast_opinions: FastOpinions,
+    sedrot: bool,
+    daf_yomi: bool,
+}
+
+impl Calendar {
+    /// Creates a query over the events of the current Hebrew year, unless a range is given.
+    #[cfg(feature = "std")]
+    pub fn new() -> Self {
+        Self::with_start(None)
+    }
+
+    /// Creates a query over the events on or after `start`, which doesn't need the clock of
+    /// the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::{Calendar, Hdate, HebrewMonth};
+    ///
+    /// let rosh_hashana = Hdate::from_ymd(5785, HebrewMonth::Tishrei, 1);
+    /// let events = Calendar::starting(rosh_hashana).events();
+    /// assert_eq!(events[0].description, "Rosh Hashana 5785");
+    /// assert_eq!(events.last().unwrap().date.year, 5785);
+    /// ```
+    pub fn starting(start: Hdate) -> Self {
+        Self::with_start(Some(start))
+    }
+
+    /// Creates a query over the events of the given Hebrew year, which doesn't need the clock
+    /// of the `std` feature.
+    pub fn for_year(year: u32) -> Self {
+        Self::with_start(None).year(year)
+    }
+
+    fn with_start(start: Option<Hdate>) -> Self {
+        Self {
+            start,
+            end: None,
+            israel: false,
+            include: None,
+            exclude: Flags::None,
+            week_days: ALL_WEEK_DAYS,
+            location: None,
+            fast_opinions: FastOpinions::default(),
+            sedrot: false,
+            daf_yomi: false,
+        }
+    }
+
+    /// Restricts the query to the given Hebrew year, from Rosh Hashana to the end of Elul.
+    pub fn year(self, year: u32) -> Self {
+        self.start(Hdate::from_ymd(year, HebrewMonth::Tishrei, 1))
+            .end(Hdate::from_ymd(year + 1, HebrewMonth::Tishrei, 1) - 1)
+    }
+
+    /// Restricts the query to events on or after the given date.
+    pub fn start(mut self, start: Hdate) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Restricts the query to events on or before the given date.
+    pub fn end(mut self, end: Hdate) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Restricts the query to events between the given Gregorian dates, inclusive.
+    ///
+    /// # Errors
+    ///
+    /// If either date comes before the creation of the world in the Hebrew calendar, a
+    /// `CalendarError::BeforeEpochError` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use hdate::Calendar;
+    ///
+    /// let events = Calendar::for_year(5785)
+    ///     .gregorian_range(
+    ///         NaiveDate::from_ymd_opt(2024, 12, 1).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+    ///     )
+    ///     .unwrap()
+    ///     .events();
+    /// assert_eq!(events.first().unwrap().description, "Rosh Chodesh Kislev");
+    /// ```
+    pub fn gregorian_range(self, start: NaiveDate, end: NaiveDate) -> Result<Self, CalendarError> {
+        let to_hdate = |date: NaiveDate| -> Result<Hdate, CalendarError> {
+            date.try_into()
+                .map_err(|_| CalendarError::BeforeEpochError(date))
+        };
+        Ok(self.start(to_hdate(start)?).end(to_hdate(end)?))
+    }
+
+    /// Selects the events observed in Israel if `true`, or in the Diaspora otherwise (the default).
+    pub fn israel(mut self, israel: bool) -> Self {
+        self.israel = israel;
+        self
+    }
+
+    /// Restricts the query to events having any of the given flags.
+    pub fn include(mut self, flags: Flags) -> Self {
+        self.include = Some(self.include.unwrap_or(Flags::None) | flags);
+        self
+    }
+
+    /// Excludes the events having any of the given flags.
+    pub fn exclude(mut self, flags: Flags) -> Self {
+        self.exclude |= flags;
+        self
+    }
+
+    /// Restricts the query to events falling on the given days of the week,
+    /// where 0 represents Sunday and 6 represents Saturday.
+    ///
+    /// # Errors
+    ///
+    /// If a day of the week is out of range, a `CalendarError::BadWeekDayArgument` is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::calendar::{Calendar, CalendarError};
+    ///
+    /// let shabbatot = Calendar::for_year(5785).week_days([6]).unwrap().events();
+    /// assert!(shabbatot.iter().all(|event| event.date.get_week_day() == 6));
+    /// assert_eq!(
+    ///     Calendar::for_year(5785).week_days([7]),
+    ///     Err(CalendarError::BadWeekDayArgument(7))
+    /// );
+    /// ```
+    pub fn week_days(
+        mut self,
+        week_days: impl IntoIterator<Item = u8>,
+    ) -> Result<Self, CalendarError> {
+        let mut mask = 0;
+        for week_day in week_days {
+            if week_day > 6 {
+                return Err(CalendarError::BadWeekDayArgument(week_day));
+            }
+            mask |= 1 << week_day;
+        }
+        self.week_days = mask;
+        Ok(self)
+    }
+
+    /// Sets the location used to calculate the time of the Chanukah candles, and includes
+    /// the beginning and end of the fasts there.
+    pub fn location(mut self, location: Location) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Sets the opinions followed for the beginning and end of the fasts.
+    pub fn fast_opinions(mut self, fast_opinions: FastOpinions) -> Self {
+        self.fast_opinions = fast_opinions;
+        self
+    }
+
+    /// Includes the weekly Torah portions read on Shabbat.
+    pub fn sedrot(mut self, sedrot: bool) -> Self {
+        self.sedrot = sedrot;
+        self
+    }
+
+    /// Includes the daily page of the Daf Yomi program.
+    pub fn daf_yomi(mut self, daf_yomi: bool) -> Self {
+        self.daf_yomi = daf_yomi;
+        self
+    }
+
+    /// Returns whether the given event satisfies all the options of the query.
+    pub fn matches(&self, event: &Event) -> bool {
+        let observed = if self.israel {
+            event.observed_in_israel()
+        } else {
+            event.observed_in_diaspora()
+        };
+        observed
+            && self.start.is_none_or(|start| event.date >= start)
+            && self.end.is_none_or(|end| event.date <= end)
+            && self
+                .include
+                .is_none_or(|include| event.mask.intersects(include))
+            && !event.mask.intersects(self.exclude)
+            && self.week_days & (1 << event.date.get_week_day()) != 0
+    }
+
+    /// Filters the given events, keeping those satisfying all the options of the query.
+    pub fn filter<'a, E: AsRef<Event>>(
+        &'a self,
+        events: impl IntoIterator<Item = E> + 'a,
+    ) -> impl Iterator<Item = E> + 'a {
+        events
+            .into_iter()
+            .filter(move |event| self.matches(event.as_ref()))
+    }
+
+    /// Returns the start of the query, and its end unless it is unbounded.
+    fn range(&self) -> (Hdate, Option<Hdate>) {
+        match (self.start, self.end) {
+            (Some(start), end) => (start, end),
+            (None, Some(end)) => (
+                Hdate::from_ymd(end.year, HebrewMonth::Tishrei, 1),
+                Some(end),
+            ),
+            #[cfg(feature = "std")]
+            (None, None) => {
+                let year = Hdate::new().year;
+                let end = Hdate::from_ymd(year + 1, HebrewMonth::Tishrei, 1) - 1;
+                (Hdate::from_ymd(year, HebrewMonth::Tishrei, 1), Some(end))
+            }
+            #[cfg(not(feature = "std"))]
+            (None, None) => unreachable!("Without the `std` feature, a query has a start"),
+        }
+    }
+
+    /// Returns whether an event flagged with some of `any` and all of `all` may satisfy the
+    /// query.
+    fn may_match(&self, any: Flags, all: Flags) -> bool {
+        // Only the events of the chosen schedule are returned.
+        let any = any.difference(if self.israel {
+            Flags::ChulOnly
+        } else {
+            Flags::IsraelOnly
+        });
+        self.week_days != 0
+            && self.include.is_none_or(|include| any.intersects(include))
+            && !all.intersects(self.exclude)
+    }
+
+    /// Returns a lazy iterator over the events satisfying the query, in chronological order.
+    ///
+    /// When a start is given without an end, the iterator never ends, unless the flags and
+    /// days of the week of the query rule out every source of events.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::{Calendar, Flags, Hdate, HebrewMonth};
+    ///
+    /// let calendar = Calendar::starting(Hdate::from_ymd(5785, HebrewMonth::Tishrei, 1))
+    ///     .include(Flags::MajorFast)
+    ///     .exclude(Flags::Erev);
+    /// let mut tisha_bav = calendar
+    ///     .iter()
+    ///     .filter(|event| event.description == "Tish'a B'Av");
+    /// assert_eq!(tisha_bav.nth(49).unwrap().date.year, 5834);
+    /// ```
+    pub fn iter(&self) -> Events<'_> {
+        let (start, end) = self.range();
+        let years =
+            move || (start.year..).take_while(move |year| end.is_none_or(|end| *year <= end.year));
+
+        // Each source comes with the flags its events may carry, and those all of them carry,
+        // so that a source none of whose events can match isn't searched forever.
+        let location = self.location;
+        let mut sources: Vec<(Flags, Flags, Source<'_>)> = vec![
+            (
+                Flags::RoshChodesh,
+                Flags::RoshChodesh,
+                yearly(years(), |year| holidays::rosh_chodesh(year..=year)),
+            ),
+            (
+                Flags::Chag
+                    | Flags::LightCandles
+                    | Flags::YomTovEnds
+                    | Flags::LightCandlesTzeis
+                    | Flags::ChulOnly
+                    | Flags::IsraelOnly
+                    | Flags::MinorFast
+                    | Flags::MajorFast
+                    | Flags::MinorHoliday
+                    | Flags::Erev
+                    | Flags::CholHamoed,
+                Flags::None,
+                yearly(years(), holidays::holidays_for_year),
+            ),
+            (
+                Flags::ModernHoliday | Flags::IsraelOnly,
+                Flags::ModernHoliday,
+                yearly(years(), holidays::modern_holidays),
+            ),
+            (
+                Flags::SpecialShabbat,
+                Flags::SpecialShabbat,
+                yearly(years(), holidays::special_shabbatot),
+            ),
+            (
+                Flags::MinorFast | Flags::YomKippurKatan,
+                Flags::MinorFast | Flags::YomKippurKatan,
+                yearly(years(), |year| {
+                    holidays::yom_kippur_katan(year, &YOM_KIPPUR_KATAN_OMITTED_MONTHS)
+                }),
+            ),
+            (
+                Flags::ShabbatMevarchim,
+                Flags::ShabbatMevarchim,
+                yearly(years(), |year| {
+                    holidays::shabbat_mevarchim(year, Locale::English)
+                }),
+            ),
+            (
+                Flags::ChanukahCandles,
+                Flags::ChanukahCandles,
+                yearly(years(), move |year| {
+                    holidays::chanukah_candles(year, location)
+                }),
+            ),
+        ];
+        if let Some(location) = self.location {
+            let fast_opinions = self.fast_opinions;
+            sources.push((
+                Flags::MinorFast | Flags::MajorFast | Flags::Erev,
+                Flags::None,
+                yearly(years(), move |year| {
+                    holidays::fast_times(year, location, fast_opinions)
+                }),
+            ));
+        }
+        if self.sedrot {
+            let israel = self.israel;
+            sources.push((
+                Flags::ParshaHashavua | Flags::IsraelOnly,
+                Flags::ParshaHashavua,
+                yearly(years(), move |year| sedra::parshiyot(year, israel)),
+            ));
+        }
+        if self.daf_yomi {
+            sources.push((
+                Flags::DafYomi,
+                Flags::DafYomi,
+                Box::new(
+                    (0..)
+                        .map(move |day| start + day)
+                        .filter_map(DafYomiEvent::new)
+                        .map(Event::from),
+                ),
+            ));
+        }
+
+        Events {
+            calendar: self,
+            end,
+            sources: sources
+                .into_iter()
+                .filter(|(any, all, _)| self.may_match(*any, *all))
+                .map(|(_, _, source)| source.peekable())
+                .collect(),
+        }
+    }
+
+    /// Generates the events satisfying the query, in chronological order.
+    ///
+    /// When a start is given without an end, the events until the end of its Hebrew year are returned.
+    pub fn events(&self) -> Vec<Event> {
+        let (start, end) = self.range();
+        let end =
+            end.unwrap_or_else(|| Hdate::from_ymd(start.year + 1, HebrewMonth::Tishrei, 1) - 1);
+        self.clone().start(start).end(end).iter().collect()
+    }
+}
+
+type Source<'a> = Box<dyn Iterator<Item = Event> + 'a>;
+
+/// Returns a source generating the events of each of the given Hebrew years, which must
+/// each be in chronological order. Only the events of the current year are kept.
+fn yearly<'a, E: Into<Event> + 'a>(
+    years: impl Iterator<Item = u32> + 'a,
+    events: impl Fn(u32) -> Vec<E> + 'a,
+) -> Source<'a> {
+    Box::new(years.flat_map(move |year| events(year).into_iter().map(Into::into)))
+}
+
+/// A lazy iterator over the events of a [`Calendar`], in chronological order.
+///
+/// Events are merged from each of the calendar's sources, which generate them one Hebrew
+/// year at a time, already in chronological order. A source allocates the events of the
+/// year it is in, so memory is bounded by a year of events however long the range is,
+/// although the iteration isn't free of allocations. Events falling on the same day are
+/// returned in the order of their sources: holidays, then the weekly parsha, then the Daf Yomi.
+pub struct Events<'a> {
+    calendar: &'a Calendar,
+    end: Option<Hdate>,
+    sources: Vec<Peekable<Source<'a>>>,
+}
+
+impl Iterator for Events<'_> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (date, index) = self
+                .sources
+                .iter_mut()
+                .enumerate()
+                .filter_map(|(index, source)| source.peek().map(|event| (event.date, index)))
+                .min()?;
+            if self.end.is_some_and(|end| date > end) {
+                return None;
+            }
+            let event = self.sources[index].next()?;
+            if self.calendar.matches(&event) {
+                return Some(event);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for Calendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CalendarEvent;
+
+    /// Returns a query from 5780, since the tests don't depend on the current year.
+    fn calendar() -> Calendar {
+        Calendar::starting(Hdate::from_ymd(5780, HebrewMonth::Tishrei, 1))
+    }
+
+    #[test]
+    fn test_matches() {
+        let shavuot_ii = Event::new(
+            Hdate::from_ymd(5785, HebrewMonth::Sivan, 7),
+            "Shavuot II".to_string(),
+            Flags::Chag | Flags::ChulOnly,
+        );
+        assert!(calendar().matches(&shavuot_ii));
+        assert!(!calendar().israel(true).matches(&shavuot_ii));
+        assert!(calendar().include(Flags::Chag).matches(&shavuot_ii));
+        assert!(!calendar().include(Flags::Erev).matches(&shavuot_ii));
+        assert!(!calendar().exclude(Flags::Chag).matches(&shavuot_ii));
+        assert!(calendar().year(5785).matches(&shavuot_ii));
+        assert!(!calendar().year(5786).matches(&shavuot_ii));
+        // 7 Sivan 5785 was a Tuesday.
+        assert!(calendar().week_days([2]).unwrap().matches(&shavuot_ii));
+        assert!(!calendar().week_days([0, 6]).unwrap().matches(&shavuot_ii));
+        assert_eq!(
+            calendar().week_days([8]),
+            Err(CalendarError::BadWeekDayArgument(8))
+        );
+        let before_epoch = NaiveDate::from_ymd_opt(-4000, 1, 1).unwrap();
+        assert_eq!(
+            calendar().gregorian_range(before_epoch, NaiveDate::MAX),
+            Err(CalendarError::BeforeEpochError(before_epoch))
+        );
+    }
+
+    #[test]
+    fn test_events() {
+        let fasts: Vec<String> = calendar()
+            .year(5785)
+            .include(Flags::MajorFast | Flags::MinorFast)
+            .exclude(Flags::YomKippurKatan | Flags::Erev)
+            .events()
+            .into_iter()
+            .map(|event| event.description)
+            .collect();
+        assert_eq!(
+            fasts,
+            [
+                "Tzom Gedaliah",
+                "Yom Kippur",
+                "Asara B'Tevet",
+                "Ta'anit Esther",
+                "Ta'anit Bechorot",
+                "Tzom Tammuz",
+                "Tish'a B'Av",
+            ]
+        );
+
+        let shabbatot = calendar()
+            .start(Hdate::from_ymd(5784, HebrewMonth::Elul, 1))
+            .end(Hdate::from_ymd(5785, HebrewMonth::Cheshvan, 1))
+            .week_days([6])
+            .unwrap()
+            .events();
+        assert!(shabbatot
+            .iter()
+            .any(|event| event.description == "Shabbat Shuva"));
+        assert!(shabbatot.iter().all(|event| event.date.get_week_day() == 6));
+        assert!(shabbatot
+            .windows(2)
+            .all(|pair| pair[0].date <= pair[1].date));
+    }
+
+    #[test]
+    fn test_filter() {
+        let calendar = calendar().israel(true);
+        let events = holidays::holidays_for_year(5785);
+        let count = calendar.filter(events).count();
+        assert_eq!(
+            count,
+            holidays::holidays_for_year(5785)
+                .iter()
+                .filter(|event| event.as_ref().observed_in_israel())
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_iter_merges_sources() {
+        let calendar = calendar()
+            .start(Hdate::from_ymd(5785, HebrewMonth::Elul, 25))
+            .end(Hdate::from_ymd(5786, HebrewMonth::Tishrei, 6))
+            .sedrot(true)
+            .daf_yomi(true);
+        let events: Vec<Event> = calendar.iter().collect();
+        assert!(events.windows(2).all(|pair| pair[0].date <= pair[1].date));
+        assert_eq!(
+            events
+                .iter()
+                .filter(|event| event.mask.contains(Flags::DafYomi))
+                .count(),
+            11
+        );
+        let shabbat: Vec<&str> = events
+            .iter()
+            .filter(|event| event.date == Hdate::from_ymd(5785, HebrewMonth::Elul, 27))
+            .map(|event| event.description.as_str())
+            .collect();
+        assert_eq!(shabbat, ["Parashat Nitzavim", "Zevachim 6"]);
+        assert!(events
+            .iter()
+            .any(|event| event.description == "Parashat Vayeilech"));
+
+        // The parshiyot of the Israel schedule keep linking to it.
+        let nitzavim = calendar
+            .israel(true)
+            .iter()
+            .find(|event| event.description == "Parashat Nitzavim")
+            .unwrap();
+        assert_eq!(
+            nitzavim.url().unwrap(),
+            "https://www.hebcal.com/sedrot/nitzavim-20250920?i=on"
+        );
+    }
+
+    #[test]
+    fn test_iter_sources_are_chronological() {
+        let jerusalem = Location::new(31.76904, 35.21633);
+        let calendar = calendar()
+            .start(Hdate::from_ymd(5760, HebrewMonth::Tishrei, 1))
+            .end(Hdate::from_ymd(5800, HebrewMonth::Elul, 29))
+            .location(jerusalem)
+            .sedrot(true);
+        let events: Vec<Event> = calendar.iter().collect();
+        assert!(events.windows(2).all(|pair| pair[0].date <= pair[1].date));
+        assert!(events
+            .iter()
+            .any(|event| event.description.starts_with("Chanukah")));
+        // The fasts keep their name, and a major fast begins on its eve.
+        let yom_kippur = events
+            .iter()
+            .find(|event| event.description == "Yom Kippur begins")
+            .unwrap();
+        assert_eq!(yom_kippur.mask, Flags::MajorFast | Flags::Erev);
+        assert_eq!(yom_kippur.date.day, 9);
+    }
+
+    #[test]
+    fn test_iter_unbounded() {
+        let calendar = calendar()
+            .start(Hdate::from_ymd(5785, HebrewMonth::Tishrei, 1))
+            .include(Flags::Chag);
+        let mut events = calendar
+            .iter()
+            .filter(|event| event.description.starts_with("Rosh Hashana 5"));
+        assert_eq!(events.nth(99).unwrap().description, "Rosh Hashana 5884");
+
+        // Queries no source can satisfy end instead of searching forever.
+        let start = Hdate::from_ymd(5785, HebrewMonth::Tishrei, 1);
+        let calendar = Calendar::starting(start).include(Flags::DafYomi);
+        assert_eq!(calendar.iter().next(), None);
+        let calendar = Calendar::starting(start)
+            .sedrot(true)
+            .include(Flags::ParshaHashavua)
+            .exclude(Flags::ParshaHashavua);
+        assert_eq!(calendar.iter().next(), None);
+        let calendar = Calendar::starting(start).week_days([]).unwrap();
+        assert_eq!(calendar.iter().next(), None);
+        let calendar = Calendar::starting(start).include(Flags::IsraelOnly);
+        assert_eq!(calendar.iter().next(), None);
+    }
+}
@@ -0,0 +1,88 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use chrono::{DateTime, Utc};
+
+use crate::locale::Locale;
+use crate::{CalendarEvent, Event, Flags, Hdate};
+
+const LIGHTING_BLESSING: &str =
+    "בָּרוּךְ אַתָּה יְיָ אֱלֹהֵינוּ מֶלֶךְ הָעוֹלָם, אֲשֶׁר קִדְּשָׁנוּ בְּמִצְוֹתָיו, וְצִוָּנוּ לְהַדְלִיק נֵר חֲנֻכָּה";
+const MIRACLES_BLESSING: &str =
+    "בָּרוּךְ אַתָּה יְיָ אֱלֹהֵינוּ מֶלֶךְ הָעוֹלָם, שֶׁעָשָׂה נִסִּים לַאֲבוֹתֵינוּ, בַּיָּמִים הָהֵם בַּזְּמַן הַזֶּה";
+const SHEHECHEYANU_BLESSING: &str = "בָּרוּךְ אַתָּה יְיָ אֱלֹהֵינוּ מֶלֶךְ הָעוֹלָם, שֶׁהֶחֱיָנוּ וְקִיְּמָנוּ וְהִגִּיעָנוּ לַזְּמַן הַזֶּה";
+
+/// The lighting of the Chanukah candles on the evening of `event.date`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChanukahCandlesEvent {
+    pub event: Event,
+    /// The number of candles lit, not counting the shamash.
+    pub candles: u8,
+    /// The time of lighting, when calculated for a location.
+    pub time: Option<DateTime<Utc>>,
+}
+
+impl ChanukahCandlesEvent {
+    pub fn new(date: Hdate, candles: u8, time: Option<DateTime<Utc>>) -> Self {
+        let description = if candles == 1 {
+            "Chanukah: 1 Candle".to_string()
+        } else {
+            format!("Chanukah: {candles} Candles")
+        };
+        Self {
+            event: Event::new(date, description, Flags::ChanukahCandles),
+            candles,
+            time,
+        }
+    }
+
+    /// Returns the blessings recited before lighting, in Hebrew.
+    ///
+    /// Shehecheyanu is only recited on the first night.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::{ChanukahCandlesEvent, Hdate, HebrewMonth};
+    ///
+    /// let first_night = ChanukahCandlesEvent::new(Hdate::from_ymd(5785, HebrewMonth::Kislev, 24), 1, None);
+    /// assert_eq!(first_night.blessings().len(), 3);
+    /// let second_night = ChanukahCandlesEvent::new(Hdate::from_ymd(5785, HebrewMonth::Kislev, 25), 2, None);
+    /// assert_eq!(second_night.blessings().len(), 2);
+    /// ```
+    pub fn blessings(&self) -> Vec<&'static str> {
+        if self.candles == 1 {
+            vec![LIGHTING_BLESSING, MIRACLES_BLESSING, SHEHECHEYANU_BLESSING]
+        } else {
+            vec![LIGHTING_BLESSING, MIRACLES_BLESSING]
+        }
+    }
+}
+
+impl AsRef<Event> for ChanukahCandlesEvent {
+    fn as_ref(&self) -> &Event {
+        &self.event
+    }
+}
+
+impl CalendarEvent for ChanukahCandlesEvent {
+    fn render(&self, locale: Locale) -> String {
+        match locale {
+            Locale::English => self.event.description.clone(),
+            Locale::Hebrew if self.candles == 1 => "חנוכה: נר 1".to_string(),
+            Locale::Hebrew => format!("חנוכה: {} נרות", self.candles),
+        }
+    }
+
+    fn emoji(&self) -> Option<&str> {
+        Some("🕎")
+    }
+}
+
+impl From<ChanukahCandlesEvent> for Event {
+    fn from(value: ChanukahCandlesEvent) -> Self {
+        value.event
+    }
+}
@@ -0,0 +1,229 @@
+use alloc::format;
+use alloc::string::String;
+
+use chrono::NaiveDate;
+use hdate_core::gregorian::gregorian_to_absolute;
+
+use crate::{CalendarEvent, Event, Flags, Hdate};
+
+/// The tractates of the Babylonian Talmud in the order they are studied, with the number
+/// of their last page. Study starts on page 2 of each tractate.
+const TRACTATES: [(&str, u16); 40] = [
+    ("Berakhot", 64),
+    ("Shabbat", 157),
+    ("Eruvin", 105),
+    ("Pesachim", 121),
+    ("Shekalim", 22),
+    ("Yoma", 88),
+    ("Sukkah", 56),
+    ("Beitzah", 40),
+    ("Rosh Hashana", 35),
+    ("Taanit", 31),
+    ("Megillah", 32),
+    ("Moed Katan", 29),
+    ("Chagigah", 27),
+    ("Yevamot", 122),
+    ("Ketubot", 112),
+    ("Nedarim", 91),
+    ("Nazir", 66),
+    ("Sotah", 49),
+    ("Gittin", 90),
+    ("Kiddushin", 82),
+    ("Bava Kamma", 119),
+    ("Bava Metzia", 119),
+    ("Bava Batra", 176),
+    ("Sanhedrin", 113),
+    ("Makkot", 24),
+    ("Shevuot", 49),
+    ("Avodah Zarah", 76),
+    ("Horayot", 14),
+    ("Zevachim", 120),
+    ("Menachot", 110),
+    ("Chullin", 142),
+    ("Bekhorot", 61),
+    ("Arakhin", 34),
+    ("Temurah", 34),
+    ("Keritot", 28),
+    ("Meilah", 22),
+    ("Kinnim", 4),
+    ("Tamid", 9),
+    ("Middot", 5),
+    ("Niddah", 73),
+];
+
+/// Kinnim, Tamid and Middot are printed in the same volume as Meilah, so their pages
+/// continue its numbering.
+const PAGE_OFFSETS: [(&str, u16); 3] = [("Kinnim", 21), ("Tamid", 24), ("Middot", 32)];
+
+/// The first cycles followed an edition of Shekalim with 13 pages, up to the seventh
+/// cycle, and later ones use the edition with 22 pages.
+const SHORT_SHEKALIM_PAGES: u16 = 13;
+const SHORT_SHEKALIM_CYCLES: i32 = 7;
+
+/// The number of days of a cycle, before and after Shekalim was lengthened.
+const SHORT_CYCLE_DAYS: i32 = 2702;
+const CYCLE_DAYS: i32 = 2711;
+
+/// The page of Talmud Bavli studied on `event.date` in the Daf Yomi program.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DafYomiEvent {
+    pub event: Event,
+    pub tractate: &'static str,
+    pub page: u16,
+}
+
+impl DafYomiEvent {
+    /// Creates the Daf Yomi event of the given date, or `None` before the program
+    /// started on 11 September 1923.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use hdate::{DafYomiEvent, Hdate};
+    ///
+    /// let date: Hdate = NaiveDate::from_ymd_opt(2020, 1, 5).unwrap().try_into().unwrap();
+    /// let daf = DafYomiEvent::new(date).unwrap();
+    /// assert_eq!(daf.event.description, "Berakhot 2");
+    /// ```
+    pub fn new(date: Hdate) -> Option<Self> {
+        let day = gregorian_to_absolute(date.into());
+        let first_day = gregorian_to_absolute(NaiveDate::from_ymd_opt(1923, 9, 11)?);
+        let eighth_cycle_day = gregorian_to_absolute(NaiveDate::from_ymd_opt(1975, 6, 24)?);
+        if day < first_day {
+            return None;
+        }
+
+        let (cycle, mut day_of_cycle) = if day >= eighth_cycle_day {
+            (
+                SHORT_SHEKALIM_CYCLES + 1 + (day - eighth_cycle_day) / CYCLE_DAYS,
+                (day - eighth_cycle_day) % CYCLE_DAYS,
+            )
+        } else {
+            (
+                1 + (day - first_day) / SHORT_CYCLE_DAYS,
+                (day - first_day) % SHORT_CYCLE_DAYS,
+            )
+        };
+
+        for (tractate, last_page) in TRACTATES {
+            let last_page = if tractate == "Shekalim" && cycle <= SHORT_SHEKALIM_CYCLES {
+                SHORT_SHEKALIM_PAGES
+            } else {
+                last_page
+            };
+            let pages = last_page as i32 - 1;
+            if day_of_cycle < pages {
+                let offset = PAGE_OFFSETS
+                    .iter()
+                    .find(|(name, _)| *name == tractate)
+                    .map_or(0, |(_, offset)| *offset);
+                let page = day_of_cycle as u16 + 2 + offset;
+                return Some(Self {
+                    event: Event::new(date, format!("{tractate} {page}"), Flags::DafYomi),
+                    tractate,
+                    page,
+                });
+            }
+            day_of_cycle -= pages;
+        }
+        unreachable!("The cycle is as long as the tractates' pages")
+    }
+}
+
+impl AsRef<Event> for DafYomiEvent {
+    fn as_ref(&self) -> &Event {
+        &self.event
+    }
+}
+
+impl CalendarEvent for DafYomiEvent {
+    /// Returns the reference of the page on Sefaria, starting from its first side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use hdate::{CalendarEvent, DafYomiEvent, Hdate};
+    ///
+    /// let date: Hdate = NaiveDate::from_ymd_opt(2020, 1, 5).unwrap().try_into().unwrap();
+    /// let daf = DafYomiEvent::new(date).unwrap();
+    /// assert_eq!(daf.reference().unwrap(), "Berakhot 2a");
+    /// assert_eq!(daf.sefaria_url().unwrap(), "https://www.sefaria.org/Berakhot.2a");
+    /// ```
+    fn reference(&self) -> Option<String> {
+        let tractate = match self.tractate {
+            "Rosh Hashana" => "Rosh Hashanah",
+            // Sefaria only holds the Jerusalem Talmud's tractate Shekalim.
+            "Shekalim" => "Jerusalem Talmud Shekalim",
+            tractate => tractate,
+        };
+        Some(format!("{tractate} {}a", self.page))
+    }
+}
+
+impl From<DafYomiEvent> for Event {
+    fn from(value: DafYomiEvent) -> Self {
+        value.event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn daf(year: i32, month: u32, day: u32) -> Option<String> {
+        let date: Hdate = NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        DafYomiEvent::new(date).map(|daf| daf.event.description)
+    }
+
+    #[test]
+    fn test_daf_yomi() {
+        assert_eq!(daf(1923, 9, 10), None);
+        assert_eq!(daf(1923, 9, 11).as_deref(), Some("Berakhot 2"));
+        assert_eq!(daf(1975, 6, 24).as_deref(), Some("Berakhot 2"));
+        assert_eq!(daf(2020, 1, 4).as_deref(), Some("Niddah 73"));
+        assert_eq!(daf(2020, 1, 5).as_deref(), Some("Berakhot 2"));
+        assert_eq!(daf(2020, 3, 8).as_deref(), Some("Shabbat 2"));
+    }
+
+    #[test]
+    fn test_daf_yomi_shekalim() {
+        // Shekalim had 13 pages in the seventh cycle, and 22 in the eighth one.
+        let shekalim_pages = |first: NaiveDate| {
+            first
+                .iter_days()
+                .take(3000)
+                .filter_map(|date| DafYomiEvent::new(date.try_into().unwrap()))
+                .skip_while(|daf| daf.tractate != "Shekalim")
+                .take_while(|daf| daf.tractate == "Shekalim")
+                .count()
+        };
+        assert_eq!(
+            shekalim_pages(NaiveDate::from_ymd_opt(1968, 5, 1).unwrap()),
+            12
+        );
+        assert_eq!(
+            shekalim_pages(NaiveDate::from_ymd_opt(1975, 6, 24).unwrap()),
+            21
+        );
+    }
+
+    #[test]
+    fn test_daf_yomi_meilah_volume() {
+        let pages: Vec<String> = NaiveDate::from_ymd_opt(2019, 9, 25)
+            .unwrap()
+            .iter_days()
+            .take(35)
+            .filter_map(|date| DafYomiEvent::new(date.try_into().unwrap()))
+            .filter(|daf| ["Kinnim", "Tamid", "Middot"].contains(&daf.tractate))
+            .map(|daf| daf.event.description)
+            .collect();
+        assert_eq!(pages.first().map(String::as_str), Some("Kinnim 23"));
+        assert_eq!(pages.last().map(String::as_str), Some("Middot 37"));
+        assert_eq!(pages.len(), 15);
+    }
+}
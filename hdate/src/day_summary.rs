@@ -0,0 +1,214 @@
+//! Everything about a single day of the calendar, gathered from the holidays, the weekly
+//! parsha, the Omer and the Daf Yomi.
+
+use alloc::vec::Vec;
+
+use crate::holidays::{chanukah_candles, year_holidays};
+use crate::sedra::parshiyot;
+use crate::{DafYomiEvent, Event, Flags, Hdate, HebrewMonth, ParshaEvent};
+
+const SATURDAY: u8 = 6;
+
+/// The number of days of the Omer, counted from the second day of Pesach until Shavuot.
+const OMER_DAYS: i32 = 49;
+
+/// The restrictions on work (melacha) of a day, from the lightest to the strictest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WorkRestriction {
+    /// A weekday, on which work is allowed.
+    None,
+    /// Chol HaMoed, on which work is allowed only to avoid a loss or for the festival.
+    CholHamoed,
+    /// Yom Tov, on which work is forbidden except for preparing food.
+    YomTov,
+    /// Shabbat and Yom Kippur, on which all work is forbidden.
+    Shabbat,
+}
+
+/// A fast day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fast {
+    /// A fast from dawn until nightfall, such as Tzom Gedaliah.
+    Minor,
+    /// A fast from sunset until nightfall of the next day: Yom Kippur and Tish'a B'Av.
+    Major,
+}
+
+/// Everything about a day, in Israel or in the Diaspora.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DaySummary {
+    pub date: Hdate,
+    pub israel: bool,
+    /// The holidays of the day, including Rosh Chodesh, fasts, special Shabbatot, modern
+    /// holidays and the days of Chanukah.
+    pub holidays: Vec<Event>,
+    pub rosh_chodesh: bool,
+    pub fast: Option<Fast>,
+    /// The day of the Omer counted in the evening preceding the day, from 1 to 49.
+    pub omer: Option<u8>,
+    /// The parsha read on the Shabbat of the week, unless it is a holiday.
+    pub parsha: Option<ParshaEvent>,
+    pub daf_yomi: Option<DafYomiEvent>,
+    /// The number of Chanukah candles lit in the evening of the day.
+    pub chanukah_candles: Option<u8>,
+    /// Whether the day precedes Shabbat or Yom Tov, whose candles are lit in the evening.
+    pub erev: bool,
+    /// Whether Shabbat or Yom Tov ends in the evening of the day, with Havdalah.
+    pub motzei: bool,
+    pub work_restriction: WorkRestriction,
+}
+
+/// Returns the holidays of `date`, as observed in Israel or in the Diaspora.
+fn observed_holidays(date: Hdate, israel: bool) -> Vec<Event> {
+    year_holidays(date.year)
+        .into_iter()
+        .filter(|event| event.date == date)
+        .filter(|event| {
+            if israel {
+                event.observed_in_israel()
+            } else {
+                event.observed_in_diaspora()
+            }
+        })
+        .collect()
+}
+
+/// Returns the restrictions on work of a day with the given holidays.
+fn restriction(date: Hdate, holidays: &[Event]) -> WorkRestriction {
+    let has = |flags| holidays.iter().any(|event| event.mask.contains(flags));
+    if date.get_week_day() == SATURDAY || has(Flags::Chag | Flags::MajorFast) {
+        WorkRestriction::Shabbat
+    } else if has(Flags::Chag) {
+        WorkRestriction::YomTov
+    } else if has(Flags::CholHamoed) {
+        WorkRestriction::CholHamoed
+    } else {
+        WorkRestriction::None
+    }
+}
+
+impl DaySummary {
+    /// Gathers everything about the given day, in Israel or in the Diaspora.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::day_summary::{DaySummary, WorkRestriction};
+    /// use hdate::{Hdate, HebrewMonth};
+    ///
+    /// let erev_shavuot = DaySummary::new(Hdate::from_ymd(5785, HebrewMonth::Sivan, 5), false);
+    /// assert_eq!(erev_shavuot.omer, Some(49));
+    /// assert!(erev_shavuot.erev);
+    ///
+    /// let shavuot = DaySummary::new(Hdate::from_ymd(5785, HebrewMonth::Sivan, 6), false);
+    /// assert_eq!(shavuot.holidays[0].description, "Shavuot I");
+    /// assert_eq!(shavuot.work_restriction, WorkRestriction::YomTov);
+    /// // Shavuot II follows in the Diaspora.
+    /// assert!(!shavuot.motzei);
+    /// assert!(DaySummary::new(shavuot.date, true).motzei);
+    /// ```
+    pub fn new(date: Hdate, israel: bool) -> Self {
+        let holidays = observed_holidays(date, israel);
+        let work_restriction = restriction(date, &holidays);
+        let tomorrow = date + 1;
+        let restricted_tomorrow =
+            restriction(tomorrow, &observed_holidays(tomorrow, israel)) >= WorkRestriction::YomTov;
+
+        let fast = holidays
+            .iter()
+            .filter(|event| !event.mask.contains(Flags::Erev))
+            .find_map(|event| {
+                if event.mask.contains(Flags::MajorFast) {
+                    Some(Fast::Major)
+                } else if event.mask.contains(Flags::MinorFast) {
+                    Some(Fast::Minor)
+                } else {
+                    None
+                }
+            });
+        let omer = date.delta_days(Hdate::from_ymd(date.year, HebrewMonth::Nisan, 15));
+        let parsha = [date.year, date.year + 1]
+            .into_iter()
+            .flat_map(|year| parshiyot(year, israel))
+            .find(|parsha| (0..7).contains(&parsha.event.date.delta_days(date)));
+
+        Self {
+            date,
+            israel,
+            rosh_chodesh: holidays
+                .iter()
+                .any(|event| event.mask.contains(Flags::RoshChodesh)),
+            fast,
+            omer: (1..=OMER_DAYS).contains(&omer).then_some(omer as u8),
+            parsha,
+            daf_yomi: DafYomiEvent::new(date),
+            chanukah_candles: chanukah_candles(date.year, None)
+                .into_iter()
+                .find(|candles| candles.event.date == date)
+                .map(|candles| candles.candles),
+            erev: work_restriction < WorkRestriction::YomTov && restricted_tomorrow,
+            motzei: work_restriction >= WorkRestriction::YomTov && !restricted_tomorrow,
+            work_restriction,
+            holidays,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_summary() {
+        let summary =
+            |month, day, israel| DaySummary::new(Hdate::from_ymd(5785, month, day), israel);
+
+        // Rosh Chodesh Tevet 5785 fell on Chanukah, on a Wednesday.
+        let rosh_chodesh_tevet = summary(HebrewMonth::Tevet, 1, false);
+        assert!(rosh_chodesh_tevet.rosh_chodesh);
+        assert_eq!(rosh_chodesh_tevet.chanukah_candles, Some(8));
+        assert_eq!(rosh_chodesh_tevet.work_restriction, WorkRestriction::None);
+        assert_eq!(
+            rosh_chodesh_tevet.parsha.unwrap().event.description,
+            "Parashat Vayigash"
+        );
+
+        let yom_kippur = summary(HebrewMonth::Tishrei, 10, false);
+        assert_eq!(yom_kippur.fast, Some(Fast::Major));
+        assert_eq!(yom_kippur.work_restriction, WorkRestriction::Shabbat);
+        assert!(yom_kippur.motzei);
+        assert!(summary(HebrewMonth::Tishrei, 9, false).erev);
+        assert_eq!(summary(HebrewMonth::Tishrei, 9, false).fast, None);
+
+        // Pesach VII 5785 was a Saturday.
+        let pesach_vii = summary(HebrewMonth::Nisan, 21, true);
+        assert_eq!(pesach_vii.work_restriction, WorkRestriction::Shabbat);
+        assert!(pesach_vii.motzei);
+        assert!(summary(HebrewMonth::Nisan, 20, true).erev);
+        assert_eq!(
+            summary(HebrewMonth::Nisan, 20, true).work_restriction,
+            WorkRestriction::CholHamoed
+        );
+
+        assert_eq!(summary(HebrewMonth::Nisan, 16, false).omer, Some(1));
+        assert_eq!(summary(HebrewMonth::Sivan, 5, false).omer, Some(49));
+        assert_eq!(summary(HebrewMonth::Sivan, 6, false).omer, None);
+        assert_eq!(
+            summary(HebrewMonth::Tamuz, 17, false).fast,
+            Some(Fast::Minor)
+        );
+
+        // The Shabbat of Chol HaMoed Sukkot has no parsha.
+        assert_eq!(summary(HebrewMonth::Tishrei, 16, false).parsha, None);
+        // Erev Rosh Hashana 5786 was a Monday, so the parsha of the week is read in 5786.
+        assert_eq!(
+            summary(HebrewMonth::Elul, 29, false)
+                .parsha
+                .unwrap()
+                .event
+                .date
+                .year,
+            5786
+        );
+    }
+}
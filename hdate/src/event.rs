@@ -1,9 +1,13 @@
+use alloc::string::String;
+
+use crate::links::{event_url, sefaria_url};
+use crate::locale::{translate, Locale};
 use crate::Hdate;
 use bitflags::bitflags;
 use chrono::NaiveDate;
 
 /// Holiday flags for Event
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Flags(u32);
 
 bitflags! {
@@ -100,7 +104,7 @@ impl Event {
     /// let event = Event::new(
     ///     Hdate::from_ymd(5749, HebrewMonth::Kislev, 26),
     ///     "Chanukah: 3 Candles".to_string(),
-    ///     Flags::None
+    ///     Flags::ChanukahCandles,
     /// );
     ///
     /// assert!(event.observed_in_israel());
@@ -127,7 +131,7 @@ impl Event {
     /// let event = Event::new(
     ///     Hdate::from_ymd(5749, HebrewMonth::Kislev, 26),
     ///     "Chanukah: 3 Candles".to_string(),
-    ///     Flags::None
+    ///     Flags::ChanukahCandles,
     /// );
     ///
     /// assert!(event.observed_in_diaspora());
@@ -140,3 +144,151 @@ impl Event {
         self.date.into()
     }
 }
+
+impl AsRef<Event> for Event {
+    fn as_ref(&self) -> &Event {
+        self
+    }
+}
+
+/// The common interface of the calendar events, whatever their type.
+///
+/// Every method has a default implementation based on the wrapped [`Event`], which
+/// event types override when they know better.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{CalendarEvent, Hdate, HebrewMonth, HolidayEvent, Flags};
+/// use hdate::holyday_event::RoshChodeshEvent;
+/// use hdate::locale::Locale;
+///
+/// let events: Vec<Box<dyn CalendarEvent>> = vec![
+///     Box::new(RoshChodeshEvent::new(Hdate::from_ymd(5785, HebrewMonth::Kislev, 1))),
+///     Box::new(HolidayEvent::new(
+///         Hdate::from_ymd(5785, HebrewMonth::Shvat, 15),
+///         "Tu BiShvat".to_string(),
+///         Flags::MinorHoliday,
+///     )),
+/// ];
+/// let titles: Vec<String> = events.iter().map(|event| event.render(Locale::Hebrew)).collect();
+/// assert_eq!(titles, ["ראש חודש כסלו", "ט״ו בשבט"]);
+/// assert_eq!(events[0].category(), "roshchodesh");
+/// ```
+pub trait CalendarEvent: AsRef<Event> {
+    /// Returns the date of the event.
+    fn date(&self) -> Hdate {
+        self.as_ref().date
+    }
+
+    /// Returns the flags of the event.
+    fn flags(&self) -> Flags {
+        self.as_ref().mask
+    }
+
+    /// Returns the title of the event in the given locale.
+    fn render(&self, locale: Locale) -> String {
+        translate(&self.as_ref().description, locale)
+    }
+
+    /// Returns an emoji illustrating the event.
+    fn emoji(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns additional details about the event.
+    fn memo(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the address of a page about the event on hebcal.com.
+    fn url(&self) -> Option<String> {
+        event_url(self.as_ref())
+    }
+
+    /// Returns the reference of the text read or studied, such as "Genesis 1:1-6:8".
+    fn reference(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns the address of the text read or studied on Sefaria.
+    fn sefaria_url(&self) -> Option<String> {
+        self.reference().map(|reference| sefaria_url(&reference))
+    }
+
+    /// Returns the category of the event, as named by hebcal.
+    fn category(&self) -> &'static str {
+        let flags = self.flags();
+        if flags.intersects(Flags::DafYomi) {
+            "dafyomi"
+        } else if flags.intersects(Flags::ParshaHashavua) {
+            "parashat"
+        } else if flags.intersects(Flags::RoshChodesh) {
+            "roshchodesh"
+        } else if flags.intersects(Flags::Molad) {
+            "molad"
+        } else if flags.intersects(Flags::ShabbatMevarchim) {
+            "mevarchim"
+        } else if flags.intersects(Flags::HebrewDate) {
+            "hebdate"
+        } else if flags.intersects(Flags::OmerCount) {
+            "omer"
+        } else {
+            "holiday"
+        }
+    }
+}
+
+impl CalendarEvent for Event {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::holidays;
+    use crate::HebrewMonth;
+
+    #[test]
+    fn test_calendar_events() {
+        let mut events: Vec<Box<dyn CalendarEvent>> = Vec::new();
+        for event in holidays::yom_kippur_katan(5785, &[]).into_iter().take(1) {
+            events.push(Box::new(event));
+        }
+        for event in holidays::shabbat_mevarchim(5785, Locale::English)
+            .into_iter()
+            .take(1)
+        {
+            events.push(Box::new(event));
+        }
+        for event in holidays::chanukah_candles(5785, None).into_iter().take(2) {
+            events.push(Box::new(event));
+        }
+        events.sort_by_key(|event| event.date());
+
+        let titles: Vec<String> = events
+            .iter()
+            .map(|event| event.render(Locale::Hebrew))
+            .collect();
+        assert_eq!(
+            titles,
+            [
+                "שבת מברכים חשון",
+                "יום כפור קטן חשון",
+                "חנוכה: נר 1",
+                "חנוכה: 2 נרות"
+            ]
+        );
+        assert!(events[0].memo().unwrap().starts_with("Molad Cheshvan"));
+        assert_eq!(events[2].emoji(), Some("🕎"));
+        assert_eq!(events[1].flags(), Flags::MinorFast | Flags::YomKippurKatan);
+        assert_eq!(
+            events[3].date(),
+            Hdate::from_ymd(5785, HebrewMonth::Kislev, 25)
+        );
+        assert!(events
+            .iter()
+            .all(|event| event.category() == "holiday" || event.category() == "mevarchim"));
+        assert!(events.iter().all(|event| event.url().is_some()));
+        let event: &Event = events[1].as_ref().as_ref();
+        assert_eq!(event.url(), events[1].url());
+    }
+}
@@ -1,8 +1,10 @@
-use std::cmp::Ordering;
-use std::fmt::Display;
-use std::ops::{Add, Sub};
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::ops::{Add, Sub};
 
-use chrono::{Local, NaiveDate};
+#[cfg(feature = "std")]
+use chrono::Local;
+use chrono::NaiveDate;
 use hdate_core::gregorian::gregorian_to_absolute;
 use hdate_core::hebrew::{self, HebrewDate, HebrewDateErrors};
 
@@ -17,7 +19,7 @@ pub struct Hdate {
 }
 
 impl Hdate {
-    /// Creates a new `Hdate` from the current system date.
+    /// Creates a new `Hdate` from the current system date. Requires the `std` feature.
     ///
     /// # Examples
     ///
@@ -26,6 +28,7 @@ impl Hdate {
     ///
     /// let hdate = Hdate::new();
     /// ```
+    #[cfg(feature = "std")]
     pub fn new() -> Self {
         // The `expect` function will panic if the conversion fails, which can never happen
         // because `Local::now()` should never be before the creation of time.
@@ -43,8 +46,12 @@ impl Hdate {
     /// use hdate::{Hdate, HebrewMonth};
     ///
     /// let hdate = Hdate::from_ymd(5782, HebrewMonth::Tishrei, 1);
+    ///
+    /// // It can also compute constants.
+    /// const PESACH_5785: Hdate = Hdate::from_ymd(5785, HebrewMonth::Nisan, 15);
+    /// assert_eq!(PESACH_5785.get_week_day(), 0);
     /// ```
-    pub fn from_ymd(year: u32, month: HebrewMonth, day: u8) -> Self {
+    pub const fn from_ymd(year: u32, month: HebrewMonth, day: u8) -> Self {
         let naive = hebrew::HebrewDate::new(year, month, day);
         let rd = naive.into_absolute();
         Self {
@@ -65,11 +72,11 @@ impl Hdate {
     /// let hdate = Hdate::from_ymd(5782, HebrewMonth::Tishrei, 1);
     /// assert!(hdate.is_leap_year());
     /// ```
-    pub fn is_leap_year(&self) -> bool {
+    pub const fn is_leap_year(&self) -> bool {
         hebrew::is_leap_year(self.year)
     }
 
-    pub fn days_in_month(&self) -> u8 {
+    pub const fn days_in_month(&self) -> u8 {
         hebrew::days_in_month(self.month, self.year)
     }
 
@@ -83,8 +90,25 @@ impl Hdate {
     /// let hdate = Hdate::from_ymd(5782, HebrewMonth::Tishrei, 1);
     /// assert_eq!(hdate.get_week_day(), 2);
     /// ```
-    pub fn get_week_day(&self) -> u8 {
-        (self.rd as f32 % 7.0).floor() as u8
+    pub const fn get_week_day(&self) -> u8 {
+        self.rd.rem_euclid(7) as u8
+    }
+
+    /// Returns the closest date on or before `self` that falls on the given day of the week,
+    /// where 0 represents Sunday and 6 represents Saturday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::{Hdate, HebrewMonth};
+    ///
+    /// let hdate = Hdate::from_ymd(5784, HebrewMonth::AdarII, 29);
+    /// let shabbat = hdate.on_or_before(6);
+    /// assert_eq!(shabbat, Hdate::from_ymd(5784, HebrewMonth::AdarII, 27));
+    /// assert_eq!(shabbat.on_or_before(6), shabbat);
+    /// ```
+    pub fn on_or_before(&self, week_day: u8) -> Self {
+        *self - (self.rd - week_day as i32).rem_euclid(7)
     }
 
     /// Returns the difference in days between the two given HDates.
@@ -99,13 +123,34 @@ impl Hdate {
     /// let days = hdate1.delta_days(hdate2);
     /// assert_eq!(days, 1);
     /// ```
-    pub fn delta_days(&self, other: Self) -> i32 {
+    pub const fn delta_days(&self, other: Self) -> i32 {
         self.rd - other.rd
     }
+
+    /// Returns whether the date is Rosh Chodesh, which Rosh Hashana is not.
+    pub(crate) fn is_rosh_chodesh(&self) -> bool {
+        self.day == 30 || (self.day == 1 && self.month != HebrewMonth::Tishrei)
+    }
+
+    /// Returns the day of Chanukah of the date, from 0 to 7, or `None` outside Chanukah.
+    pub(crate) fn chanukah_day(&self) -> Option<usize> {
+        let day = self.delta_days(Self::from_ymd(self.year, HebrewMonth::Kislev, 25));
+        (0..8).contains(&day).then_some(day as usize)
+    }
+
+    pub(crate) fn is_chanukah(&self) -> bool {
+        self.chanukah_day().is_some()
+    }
+
+    /// Returns whether the date is Purim, in Adar II of leap years.
+    pub(crate) fn is_purim(&self) -> bool {
+        self.month == HebrewMonth::from(hebrew::months_in_year(self.year)) && self.day == 14
+    }
 }
 
 // Traits implementations
 
+#[cfg(feature = "std")]
 impl Default for Hdate {
     fn default() -> Self {
         Self::new()
@@ -171,7 +216,14 @@ impl PartialEq for Hdate {
 impl PartialOrd for Hdate {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.rd.partial_cmp(&other.rd)
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hdate {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rd.cmp(&other.rd)
     }
 }
 
@@ -197,7 +249,7 @@ impl From<Hdate> for NaiveDate {
 }
 
 impl Display for Hdate {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{} {} {}", self.day, self.month, self.year)
     }
 }
@@ -1,4 +1,8 @@
-use crate::{Event, Flags, Hdate};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use crate::locale::{month_name, Locale};
+use crate::{CalendarEvent, Event, Flags, Hdate};
 
 #[derive(Debug, Clone)]
 pub struct HebrewDateEvent(pub Event);
@@ -8,3 +12,24 @@ impl HebrewDateEvent {
         Self(Event::new(date, date.to_string(), Flags::HebrewDate))
     }
 }
+
+impl AsRef<Event> for HebrewDateEvent {
+    fn as_ref(&self) -> &Event {
+        &self.0
+    }
+}
+
+impl CalendarEvent for HebrewDateEvent {
+    fn render(&self, locale: Locale) -> String {
+        let date = self.date();
+        match locale {
+            Locale::English => self.0.description.clone(),
+            Locale::Hebrew => format!(
+                "{} {} {}",
+                date.day,
+                month_name(date.month, date.year, locale),
+                date.year
+            ),
+        }
+    }
+}
@@ -0,0 +1,1685 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use chrono::Duration;
+
+use hdate_core::hebrew::{days_in_month, is_leap_year, months_in_year};
+use hdate_core::tekufah::birkat_hachamah;
+
+use crate::chanukah_event::ChanukahCandlesEvent;
+use crate::holyday_event::{
+    HolidayEvent, RoshChodeshEvent, ShabbatMevarchimEvent, YomKippurKatanEvent,
+};
+use crate::locale::{day_name, month_name, translate, Locale};
+use crate::molad_event::Molad;
+use crate::timed_event::TimedEvent;
+use crate::zmanim::{ALOT_HASHACHAR_DEGREES, TZEIT_DEGREES};
+use crate::{Event, Flags, Hdate, HebrewMonth, Location, Zmanim};
+
+const SUNDAY: u8 = 0;
+const TUESDAY: u8 = 2;
+const THURSDAY: u8 = 4;
+const FRIDAY: u8 = 5;
+const SATURDAY: u8 = 6;
+
+/// The months whose Rosh Chodesh is not preceded by Yom Kippur Katan, per common custom.
+///
+/// Rosh Hashana is not a Rosh Chodesh, Erev Rosh Chodesh Cheshvan follows Yom Kippur by
+/// a few weeks, Erev Rosh Chodesh Tevet falls on Chanukah, and one doesn't fast in Nisan.
+pub const YOM_KIPPUR_KATAN_OMITTED_MONTHS: [HebrewMonth; 4] = [
+    HebrewMonth::Tishrei,
+    HebrewMonth::Cheshvan,
+    HebrewMonth::Tevet,
+    HebrewMonth::Iyyar,
+];
+
+/// Returns the months of the given Hebrew year, in the order they occur (Tishrei to Elul).
+fn months_of_year(year: u32) -> impl Iterator<Item = HebrewMonth> {
+    (HebrewMonth::Tishrei as u8..=months_in_year(year))
+        .chain(HebrewMonth::Nisan as u8..=HebrewMonth::Elul as u8)
+        .map(HebrewMonth::from)
+}
+
+/// Returns the month preceding `month` in the given Hebrew year, along with its year.
+fn previous_month(month: HebrewMonth, year: u32) -> (HebrewMonth, u32) {
+    match month {
+        HebrewMonth::Tishrei => (HebrewMonth::Elul, year - 1),
+        HebrewMonth::Nisan => (months_in_year(year).into(), year),
+        _ => ((month as u8 - 1).into(), year),
+    }
+}
+
+/// Returns the days of Rosh Chodesh for the given month.
+///
+/// When the previous month has 30 days, Rosh Chodesh is observed on both
+/// its 30th day and on the 1st of the new month.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{holidays, Hdate, HebrewMonth};
+///
+/// let days = holidays::rosh_chodesh_dates(HebrewMonth::Kislev, 5785);
+/// assert_eq!(
+///     days,
+///     vec![
+///         Hdate::from_ymd(5785, HebrewMonth::Cheshvan, 30),
+///         Hdate::from_ymd(5785, HebrewMonth::Kislev, 1),
+///     ]
+/// );
+/// ```
+pub fn rosh_chodesh_dates(month: HebrewMonth, year: u32) -> Vec<Hdate> {
+    let (previous, previous_year) = previous_month(month, year);
+    let first = Hdate::from_ymd(year, month, 1);
+    if days_in_month(previous, previous_year) == 30 {
+        vec![first - 1, first]
+    } else {
+        vec![first]
+    }
+}
+
+/// Returns the Rosh Chodesh events of the given range of Hebrew years, in chronological order.
+///
+/// Rosh Hashana is not a Rosh Chodesh, so Tishrei is skipped.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{holidays, Hdate, HebrewMonth};
+///
+/// let events = holidays::rosh_chodesh(5784..=5784);
+/// assert_eq!(events.len(), 18);
+/// assert_eq!(
+///     events[0].0.as_ref().date,
+///     Hdate::from_ymd(5784, HebrewMonth::Tishrei, 30)
+/// );
+/// assert_eq!(events[0].0.as_ref().description, "Rosh Chodesh Cheshvan");
+/// ```
+pub fn rosh_chodesh(years: RangeInclusive<u32>) -> Vec<RoshChodeshEvent> {
+    years
+        .flat_map(|year| {
+            months_of_year(year)
+                .filter(|month| *month != HebrewMonth::Tishrei)
+                .flat_map(move |month| rosh_chodesh_dates(month, year))
+        })
+        .map(RoshChodeshEvent::new)
+        .collect()
+}
+
+/// Returns the Yom Kippur Katan events of the given Hebrew year.
+///
+/// Yom Kippur Katan is observed on the day before Rosh Chodesh, and moved back to
+/// Thursday when it falls on Friday or Shabbat. It isn't observed ahead of the months
+/// listed in `omit`, which is usually [`YOM_KIPPUR_KATAN_OMITTED_MONTHS`].
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{holidays, Hdate, HebrewMonth};
+///
+/// let events = holidays::yom_kippur_katan(5785, &holidays::YOM_KIPPUR_KATAN_OMITTED_MONTHS);
+/// assert_eq!(events.len(), 8);
+/// assert_eq!(events[0].as_ref().description, "Yom Kippur Katan Kislev");
+/// assert_eq!(events[0].of_month, HebrewMonth::Kislev);
+/// // Cheshvan 29, 5785 is a Shabbat.
+/// assert_eq!(
+///     events[0].as_ref().date,
+///     Hdate::from_ymd(5785, HebrewMonth::Cheshvan, 27)
+/// );
+/// ```
+pub fn yom_kippur_katan(year: u32, omit: &[HebrewMonth]) -> Vec<YomKippurKatanEvent> {
+    months_of_year(year)
+        .filter_map(|month| {
+            let of_month = next_month(month, year);
+            if omit.contains(&of_month) {
+                return None;
+            }
+            let date = Hdate::from_ymd(year, month, 29);
+            let date = match date.get_week_day() {
+                FRIDAY | SATURDAY => date.on_or_before(THURSDAY),
+                _ => date,
+            };
+            Some(YomKippurKatanEvent::new(date, of_month))
+        })
+        .collect()
+}
+
+/// Returns the special Shabbatot of the given Hebrew year, in chronological order.
+///
+/// In a leap year, the four parshiyot (Shekalim, Zachor, Parah and HaChodesh) are read
+/// in Adar II. When Rosh Chodesh Adar or Rosh Chodesh Nisan falls on Shabbat, that very
+/// Shabbat is Shabbat Shekalim or Shabbat HaChodesh.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{holidays, Hdate, HebrewMonth};
+///
+/// let events = holidays::special_shabbatot(5785);
+/// assert_eq!(events.len(), 9);
+/// assert_eq!(events[3].as_ref().description, "Shabbat Zachor");
+/// assert_eq!(
+///     events[3].as_ref().date,
+///     Hdate::from_ymd(5785, HebrewMonth::AdarI, 8)
+/// );
+/// ```
+pub fn special_shabbatot(year: u32) -> Vec<HolidayEvent> {
+    let adar = HebrewMonth::from(months_in_year(year));
+    let rosh_hashana = Hdate::from_ymd(year, HebrewMonth::Tishrei, 1);
+    // Parashat Beshalach is read fifteen weeks after Bereshit, as no parsha is ever
+    // doubled or displaced by a holiday in between.
+    let bereshit = Hdate::from_ymd(year, HebrewMonth::Tishrei, 29).on_or_before(SATURDAY);
+    let rosh_chodesh_nisan = Hdate::from_ymd(year, HebrewMonth::Nisan, 1);
+    let tisha_bav = Hdate::from_ymd(year, HebrewMonth::Av, 9);
+
+    let hachodesh = rosh_chodesh_nisan.on_or_before(SATURDAY);
+    [
+        ((rosh_hashana + 7).on_or_before(SATURDAY), "Shabbat Shuva"),
+        (bereshit + 15 * 7, "Shabbat Shirah"),
+        (
+            Hdate::from_ymd(year, adar, 1).on_or_before(SATURDAY),
+            "Shabbat Shekalim",
+        ),
+        (
+            Hdate::from_ymd(year, adar, 13).on_or_before(SATURDAY),
+            "Shabbat Zachor",
+        ),
+        (hachodesh - 7, "Shabbat Parah"),
+        (hachodesh, "Shabbat HaChodesh"),
+        (
+            Hdate::from_ymd(year, HebrewMonth::Nisan, 14).on_or_before(SATURDAY),
+            "Shabbat HaGadol",
+        ),
+        (tisha_bav.on_or_before(SATURDAY), "Shabbat Chazon"),
+        ((tisha_bav + 7).on_or_before(SATURDAY), "Shabbat Nachamu"),
+    ]
+    .into_iter()
+    .map(|(date, description)| {
+        HolidayEvent::new(date, description.to_string(), Flags::SpecialShabbat)
+    })
+    .collect()
+}
+
+/// Returns the candle-lighting events of the eight nights of Chanukah of the given Hebrew year.
+///
+/// When a location is given, each event holds its lighting time: at dusk on weekdays,
+/// right before the Shabbat candles on Friday, and after Havdalah on Saturday night.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{holidays, Hdate, HebrewMonth, Location};
+///
+/// let new_york = Location::new(40.71427, -74.00597);
+/// let events = holidays::chanukah_candles(5785, Some(new_york));
+/// assert_eq!(events.len(), 8);
+/// assert_eq!(events[2].event.description, "Chanukah: 3 Candles");
+/// assert_eq!(events[2].event.date, Hdate::from_ymd(5785, HebrewMonth::Kislev, 26));
+/// assert!(events[2].time.is_some());
+/// ```
+pub fn chanukah_candles(year: u32, location: Option<Location>) -> Vec<ChanukahCandlesEvent> {
+    let first_night = Hdate::from_ymd(year, HebrewMonth::Kislev, 24);
+    (1..=8)
+        .map(|candles| {
+            let date = first_night + (candles as i32 - 1);
+            let time = location.and_then(|location| {
+                let zmanim = Zmanim::new(date.into(), location);
+                match date.get_week_day() {
+                    FRIDAY => zmanim.candle_lighting(),
+                    SATURDAY => zmanim.tzeit(TZEIT_DEGREES),
+                    _ => zmanim.dusk(),
+                }
+            });
+            ChanukahCandlesEvent::new(date, candles, time)
+        })
+        .collect()
+}
+
+/// The opinions followed for the times at which fasts begin and end.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FastOpinions {
+    /// Degrees below the horizon at dawn, when minor fasts begin.
+    pub alot_hashachar_degrees: f64,
+    /// Degrees below the horizon at nightfall, when fasts end.
+    pub tzeit_degrees: f64,
+    /// Minutes before sunset at which Yom Kippur and Tish'a B'Av begin, to add from the
+    /// weekday to the fast.
+    pub minutes_before_sunset: i64,
+}
+
+impl Default for FastOpinions {
+    fn default() -> Self {
+        Self {
+            alot_hashachar_degrees: ALOT_HASHACHAR_DEGREES,
+            tzeit_degrees: TZEIT_DEGREES,
+            minutes_before_sunset: 0,
+        }
+    }
+}
+
+/// Returns the beginning and end of each fast of the given Hebrew year, at the given
+/// location.
+///
+/// Minor fasts last from dawn until nightfall. Yom Kippur and Tish'a B'Av begin at sunset
+/// of the previous evening, so their beginning is also flagged [`Flags::Erev`].
+///
+/// # Examples
+///
+/// ```
+/// use hdate::holidays::{self, FastOpinions};
+/// use hdate::{Hdate, HebrewMonth, Location};
+///
+/// let jerusalem = Location::new(31.76904, 35.21633);
+/// let events = holidays::fast_times(5785, jerusalem, FastOpinions::default());
+/// assert_eq!(events[0].event.description, "Tzom Gedaliah begins");
+/// assert_eq!(events[0].holiday, "Tzom Gedaliah");
+/// assert_eq!(events[0].event.date, Hdate::from_ymd(5785, HebrewMonth::Tishrei, 4));
+/// // Yom Kippur begins on the evening of Erev Yom Kippur.
+/// assert_eq!(events[2].event.description, "Yom Kippur begins");
+/// assert_eq!(events[2].event.date, Hdate::from_ymd(5785, HebrewMonth::Tishrei, 9));
+/// assert_eq!(events[3].event.description, "Yom Kippur ends");
+/// assert!(events[3].time.unwrap() > events[2].time.unwrap());
+/// ```
+pub fn fast_times(year: u32, location: Location, opinions: FastOpinions) -> Vec<TimedEvent> {
+    let zmanim = |date: Hdate| Zmanim::new(date.into(), location);
+    holidays_for_year(year)
+        .into_iter()
+        .map(|event| event.as_ref().clone())
+        .filter(|event| {
+            event.mask.intersects(Flags::MinorFast | Flags::MajorFast)
+                && !event.mask.contains(Flags::Erev)
+        })
+        .flat_map(|fast| {
+            let mask = fast.mask & (Flags::MinorFast | Flags::MajorFast);
+            let begins = if mask.contains(Flags::MajorFast) {
+                let eve = fast.date - 1;
+                let sunset = zmanim(eve).sunset();
+                let begins_at =
+                    sunset.map(|sunset| sunset - Duration::minutes(opinions.minutes_before_sunset));
+                TimedEvent::new(eve, &fast.description, true, mask | Flags::Erev, begins_at)
+            } else {
+                let alot = zmanim(fast.date).time_at_angle(opinions.alot_hashachar_degrees, true);
+                TimedEvent::new(fast.date, &fast.description, true, mask, alot)
+            };
+            let ends_at = zmanim(fast.date).tzeit(opinions.tzeit_degrees);
+            [
+                begins,
+                TimedEvent::new(fast.date, &fast.description, false, mask, ends_at),
+            ]
+        })
+        .collect()
+}
+
+/// Returns the Shabbat Mevarchim events of the given Hebrew year.
+///
+/// Shabbat Mevarchim is the Shabbat preceding each Rosh Chodesh, except for the
+/// one preceding Rosh Hashana. The memo of each event holds the announcement of
+/// the molad and of the days of the upcoming Rosh Chodesh, in the given locale.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{holidays, Hdate, HebrewMonth};
+/// use hdate::locale::Locale;
+///
+/// let events = holidays::shabbat_mevarchim(5785, Locale::English);
+/// assert_eq!(events.len(), 11);
+/// assert_eq!(
+///     events[1].memo,
+///     "Molad Kislev: Sun, 49 minutes and 15 chalakim after 4:00. \
+///      Rosh Chodesh Kislev will be on Sunday and Monday"
+/// );
+/// ```
+pub fn shabbat_mevarchim(year: u32, locale: Locale) -> Vec<ShabbatMevarchimEvent> {
+    months_of_year(year)
+        .filter(|month| *month != HebrewMonth::Elul)
+        .map(|month| {
+            let date = Hdate::from_ymd(year, month, 29).on_or_before(SATURDAY);
+            let of_month = next_month(month, year);
+            let memo = mevarchim_announcement(of_month, year, locale);
+            ShabbatMevarchimEvent::new(date, of_month, Some(memo))
+        })
+        .collect()
+}
+
+/// Returns the month following `month` in the given Hebrew year.
+fn next_month(month: HebrewMonth, year: u32) -> HebrewMonth {
+    if month as u8 == months_in_year(year) {
+        HebrewMonth::Nisan
+    } else {
+        (month as u8 + 1).into()
+    }
+}
+
+fn mevarchim_announcement(month: HebrewMonth, year: u32, locale: Locale) -> String {
+    let molad = Molad::new(year, month).announcement(locale);
+    let days: Vec<&str> = rosh_chodesh_dates(month, year)
+        .iter()
+        .map(|date| day_name(date.get_week_day(), locale))
+        .collect();
+    let name = month_name(month, year, locale);
+    match locale {
+        Locale::English => format!(
+            "{molad}. Rosh Chodesh {name} will be on {}",
+            days.join(" and ")
+        ),
+        Locale::Hebrew => format!(
+            "{molad}. ראש חודש {name} יהיה ביום {}",
+            days.join(" וביום ")
+        ),
+    }
+}
+
+/// How a modern holiday is moved when it falls on a day the Knesset avoids.
+#[derive(Clone, Copy)]
+enum Postponement {
+    None,
+    /// From Shabbat to the following Sunday.
+    SaturdayToSunday,
+    /// From Friday or Shabbat to the following Sunday.
+    WeekendToSunday,
+    /// From Friday or Shabbat to the preceding Thursday.
+    WeekendToThursday,
+}
+
+/// A modern holiday set on a fixed Hebrew date, since the year it was instituted.
+struct ModernHoliday {
+    since: u32,
+    month: HebrewMonth,
+    day: u8,
+    description: &'static str,
+    postponement: Postponement,
+    flags: Flags,
+}
+
+const MODERN_HOLIDAYS: [ModernHoliday; 9] = [
+    ModernHoliday {
+        since: 5727,
+        month: HebrewMonth::Iyyar,
+        day: 28,
+        description: "Yom Yerushalayim",
+        postponement: Postponement::None,
+        flags: Flags::ModernHoliday,
+    },
+    ModernHoliday {
+        since: 5777,
+        month: HebrewMonth::Nisan,
+        day: 10,
+        description: "Yom HaAliyah",
+        postponement: Postponement::None,
+        flags: Flags::ModernHoliday,
+    },
+    ModernHoliday {
+        since: 5769,
+        month: HebrewMonth::Cheshvan,
+        day: 29,
+        description: "Sigd",
+        postponement: Postponement::None,
+        flags: Flags::ModernHoliday,
+    },
+    ModernHoliday {
+        since: 5737,
+        month: HebrewMonth::Kislev,
+        day: 6,
+        description: "Ben-Gurion Day",
+        postponement: Postponement::WeekendToSunday,
+        flags: Flags::ModernHoliday.union(Flags::IsraelOnly),
+    },
+    ModernHoliday {
+        since: 5750,
+        month: HebrewMonth::Shvat,
+        day: 30,
+        description: "Family Day",
+        postponement: Postponement::None,
+        flags: Flags::ModernHoliday.union(Flags::IsraelOnly),
+    },
+    ModernHoliday {
+        since: 5758,
+        month: HebrewMonth::Cheshvan,
+        day: 12,
+        description: "Yitzhak Rabin Memorial Day",
+        postponement: Postponement::WeekendToThursday,
+        flags: Flags::ModernHoliday.union(Flags::IsraelOnly),
+    },
+    ModernHoliday {
+        since: 5764,
+        month: HebrewMonth::Iyyar,
+        day: 10,
+        description: "Herzl Day",
+        postponement: Postponement::SaturdayToSunday,
+        flags: Flags::ModernHoliday.union(Flags::IsraelOnly),
+    },
+    ModernHoliday {
+        since: 5765,
+        month: HebrewMonth::Tamuz,
+        day: 29,
+        description: "Jabotinsky Day",
+        postponement: Postponement::SaturdayToSunday,
+        flags: Flags::ModernHoliday.union(Flags::IsraelOnly),
+    },
+    ModernHoliday {
+        since: 5773,
+        month: HebrewMonth::Tevet,
+        day: 21,
+        description: "Hebrew Language Day",
+        postponement: Postponement::WeekendToThursday,
+        flags: Flags::ModernHoliday.union(Flags::IsraelOnly),
+    },
+];
+
+/// Returns the modern Israeli holidays of the given Hebrew year, in chronological order.
+///
+/// Each holiday is moved according to the rules set by the Knesset when it falls on
+/// a day that would desecrate Shabbat, and is only returned from the year it was
+/// instituted. Days commemorated only by the Knesset are flagged [`Flags::IsraelOnly`].
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{holidays, Hdate, HebrewMonth};
+///
+/// let events = holidays::modern_holidays(5784);
+/// let atzmaut = events
+///     .iter()
+///     .find(|event| event.as_ref().description == "Yom HaAtzma'ut")
+///     .unwrap();
+/// // 5 Iyyar 5784 fell on a Monday, so Yom HaAtzma'ut was postponed to Tuesday.
+/// assert_eq!(atzmaut.as_ref().date, Hdate::from_ymd(5784, HebrewMonth::Iyyar, 6));
+/// assert!(holidays::modern_holidays(5700).is_empty());
+/// ```
+pub fn modern_holidays(year: u32) -> Vec<HolidayEvent> {
+    let mut events = Vec::new();
+
+    if year >= 5711 {
+        let yom_hashoah = Hdate::from_ymd(year, HebrewMonth::Nisan, 27);
+        let yom_hashoah = match yom_hashoah.get_week_day() {
+            FRIDAY => yom_hashoah - 1,
+            SUNDAY => yom_hashoah + 1,
+            _ => yom_hashoah,
+        };
+        events.push(HolidayEvent::new(
+            yom_hashoah,
+            "Yom HaShoah".to_string(),
+            Flags::ModernHoliday,
+        ));
+    }
+
+    if year >= 5708 {
+        // Yom HaZikaron is set from the day of the week on which Pesach falls, as it
+        // determines the day of the week of 4 Iyyar.
+        let day = match Hdate::from_ymd(year, HebrewMonth::Nisan, 15).get_week_day() {
+            SUNDAY => 2,
+            SATURDAY => 3,
+            _ if year < 5764 => 4,
+            TUESDAY => 5,
+            _ => 4,
+        };
+        let yom_hazikaron = Hdate::from_ymd(year, HebrewMonth::Iyyar, day);
+        events.push(HolidayEvent::new(
+            yom_hazikaron,
+            "Yom HaZikaron".to_string(),
+            Flags::ModernHoliday,
+        ));
+        events.push(HolidayEvent::new(
+            yom_hazikaron + 1,
+            "Yom HaAtzma'ut".to_string(),
+            Flags::ModernHoliday,
+        ));
+    }
+
+    for holiday in MODERN_HOLIDAYS
+        .iter()
+        .filter(|holiday| year >= holiday.since)
+    {
+        let date = Hdate::from_ymd(year, holiday.month, holiday.day);
+        let date = match (holiday.postponement, date.get_week_day()) {
+            (Postponement::SaturdayToSunday, SATURDAY) => date + 1,
+            (Postponement::WeekendToSunday, FRIDAY) => date + 2,
+            (Postponement::WeekendToSunday, SATURDAY) => date + 1,
+            (Postponement::WeekendToThursday, FRIDAY | SATURDAY) => date.on_or_before(THURSDAY),
+            _ => date,
+        };
+        events.push(HolidayEvent::new(
+            date,
+            holiday.description.to_string(),
+            holiday.flags,
+        ));
+    }
+
+    events.sort_by_key(|event| event.as_ref().date);
+    events
+}
+
+const ROMAN_NUMERALS: [&str; 6] = ["I", "II", "III", "IV", "V", "VI"];
+
+/// Returns the first six days of Pesach or Sukkot starting on `first_day`, as observed in
+/// Israel or in the Diaspora, where Yom Tov lasts two days and Chol HaMoed one day less.
+///
+/// When `erev_yom_tov` is `true`, the sixth day leads into Yom Tov and requires candle-lighting.
+fn festival_days(
+    name: &'static str,
+    first_day: Hdate,
+    israel: bool,
+    erev_yom_tov: bool,
+) -> impl Iterator<Item = HolidayEvent> {
+    let (yom_tov_days, location) = if israel {
+        (1, Flags::IsraelOnly)
+    } else {
+        (2, Flags::ChulOnly)
+    };
+    ROMAN_NUMERALS
+        .iter()
+        .enumerate()
+        .map(move |(day, numeral)| {
+            let (description, flags) = if day + 1 < yom_tov_days {
+                (
+                    format!("{name} {numeral}"),
+                    Flags::Chag | Flags::LightCandlesTzeis,
+                )
+            } else if day + 1 == yom_tov_days {
+                (format!("{name} {numeral}"), Flags::Chag | Flags::YomTovEnds)
+            } else if day == 5 && erev_yom_tov {
+                (
+                    format!("{name} {numeral} (CH''M)"),
+                    Flags::CholHamoed | Flags::LightCandles,
+                )
+            } else {
+                (format!("{name} {numeral} (CH''M)"), Flags::CholHamoed)
+            };
+            HolidayEvent::new(first_day + day as i32, description, flags | location)
+        })
+}
+
+/// Returns the holidays and fasts of the given Hebrew year, in chronological order.
+///
+/// This includes Yom Tov, the Erev and Chol HaMoed days, the minor holidays and the
+/// fasts, moved when they fall on Shabbat. Days observed differently in Israel and in
+/// the Diaspora are returned once for each, flagged [`Flags::IsraelOnly`] or
+/// [`Flags::ChulOnly`], such as the second day of Yom Tov. Rosh Chodesh, the special
+/// Shabbatot and the modern holidays have generators of their own.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{holidays, Flags, Hdate, HebrewMonth};
+///
+/// let events = holidays::holidays_for_year(5785);
+/// let pesach_iii: Vec<_> = events
+///     .iter()
+///     .map(|event| event.as_ref())
+///     .filter(|event| event.date == Hdate::from_ymd(5785, HebrewMonth::Nisan, 17))
+///     .collect();
+/// assert_eq!(pesach_iii[0].description, "Pesach III (CH''M)");
+/// assert!(pesach_iii.iter().all(|event| event.mask.contains(Flags::CholHamoed)));
+/// ```
+pub fn holidays_for_year(year: u32) -> Vec<HolidayEvent> {
+    let date = |month, day| Hdate::from_ymd(year, month, day);
+    let event =
+        |date, description: &str, flags| HolidayEvent::new(date, description.to_string(), flags);
+    // Fasts falling on Shabbat are postponed to Sunday, except for Ta'anit Esther and
+    // Ta'anit Bechorot which are brought forward to Thursday.
+    let postponed = |date: Hdate| {
+        if date.get_week_day() == SATURDAY {
+            date + 1
+        } else {
+            date
+        }
+    };
+    let brought_forward = |date: Hdate| {
+        if date.get_week_day() == SATURDAY {
+            date - 2
+        } else {
+            date
+        }
+    };
+    let adar = HebrewMonth::from(months_in_year(year));
+    let tisha_bav = postponed(date(HebrewMonth::Av, 9));
+    let rosh_hashana = Hdate::from_ymd(year + 1, HebrewMonth::Tishrei, 1);
+
+    let mut events = vec![
+        event(
+            date(HebrewMonth::Tishrei, 1),
+            &format!("Rosh Hashana {year}"),
+            Flags::Chag | Flags::LightCandlesTzeis,
+        ),
+        event(
+            date(HebrewMonth::Tishrei, 2),
+            "Rosh Hashana II",
+            Flags::Chag | Flags::YomTovEnds,
+        ),
+        event(
+            postponed(date(HebrewMonth::Tishrei, 3)),
+            "Tzom Gedaliah",
+            Flags::MinorFast,
+        ),
+        event(
+            date(HebrewMonth::Tishrei, 9),
+            "Erev Yom Kippur",
+            Flags::Erev | Flags::LightCandles,
+        ),
+        event(
+            date(HebrewMonth::Tishrei, 10),
+            "Yom Kippur",
+            Flags::Chag | Flags::MajorFast,
+        ),
+        event(
+            date(HebrewMonth::Tishrei, 14),
+            "Erev Sukkot",
+            Flags::Erev | Flags::LightCandles,
+        ),
+        event(
+            date(HebrewMonth::Tishrei, 21),
+            "Sukkot VII (Hoshana Raba)",
+            Flags::CholHamoed | Flags::LightCandles,
+        ),
+        event(
+            date(HebrewMonth::Tishrei, 22),
+            "Shmini Atzeret",
+            Flags::Chag | Flags::YomTovEnds | Flags::IsraelOnly,
+        ),
+        event(
+            date(HebrewMonth::Tishrei, 22),
+            "Shmini Atzeret",
+            Flags::Chag | Flags::LightCandlesTzeis | Flags::ChulOnly,
+        ),
+        event(
+            date(HebrewMonth::Tishrei, 23),
+            "Simchat Torah",
+            Flags::Chag | Flags::YomTovEnds | Flags::ChulOnly,
+        ),
+        event(
+            date(HebrewMonth::Kislev, 24) + 8,
+            "Chanukah: 8th Day",
+            Flags::MinorHoliday,
+        ),
+        event(
+            date(HebrewMonth::Tevet, 10),
+            "Asara B'Tevet",
+            Flags::MinorFast,
+        ),
+        event(
+            date(HebrewMonth::Shvat, 15),
+            "Tu BiShvat",
+            Flags::MinorHoliday,
+        ),
+        event(
+            brought_forward(date(adar, 13)),
+            "Ta'anit Esther",
+            Flags::MinorFast,
+        ),
+        event(
+            date(adar, 13),
+            "Erev Purim",
+            Flags::Erev | Flags::MinorHoliday,
+        ),
+        event(date(adar, 14), "Purim", Flags::MinorHoliday),
+        event(date(adar, 15), "Shushan Purim", Flags::MinorHoliday),
+        event(
+            brought_forward(date(HebrewMonth::Nisan, 14)),
+            "Ta'anit Bechorot",
+            Flags::MinorFast,
+        ),
+        event(
+            date(HebrewMonth::Nisan, 14),
+            "Erev Pesach",
+            Flags::Erev | Flags::LightCandles,
+        ),
+        event(
+            date(HebrewMonth::Nisan, 21),
+            "Pesach VII",
+            Flags::Chag | Flags::YomTovEnds | Flags::IsraelOnly,
+        ),
+        event(
+            date(HebrewMonth::Nisan, 21),
+            "Pesach VII",
+            Flags::Chag | Flags::LightCandlesTzeis | Flags::ChulOnly,
+        ),
+        event(
+            date(HebrewMonth::Nisan, 22),
+            "Pesach VIII",
+            Flags::Chag | Flags::YomTovEnds | Flags::ChulOnly,
+        ),
+        event(
+            date(HebrewMonth::Iyyar, 14),
+            "Pesach Sheni",
+            Flags::MinorHoliday,
+        ),
+        event(
+            date(HebrewMonth::Iyyar, 18),
+            "Lag BaOmer",
+            Flags::MinorHoliday,
+        ),
+        event(
+            date(HebrewMonth::Sivan, 5),
+            "Erev Shavuot",
+            Flags::Erev | Flags::LightCandles,
+        ),
+        event(
+            date(HebrewMonth::Sivan, 6),
+            "Shavuot",
+            Flags::Chag | Flags::YomTovEnds | Flags::IsraelOnly,
+        ),
+        event(
+            date(HebrewMonth::Sivan, 6),
+            "Shavuot I",
+            Flags::Chag | Flags::LightCandlesTzeis | Flags::ChulOnly,
+        ),
+        event(
+            date(HebrewMonth::Sivan, 7),
+            "Shavuot II",
+            Flags::Chag | Flags::YomTovEnds | Flags::ChulOnly,
+        ),
+        event(
+            postponed(date(HebrewMonth::Tamuz, 17)),
+            "Tzom Tammuz",
+            Flags::MinorFast,
+        ),
+        event(
+            tisha_bav - 1,
+            "Erev Tish'a B'Av",
+            Flags::Erev | Flags::MajorFast,
+        ),
+        event(tisha_bav, "Tish'a B'Av", Flags::MajorFast),
+        event(date(HebrewMonth::Av, 15), "Tu B'Av", Flags::MinorHoliday),
+        event(
+            date(HebrewMonth::Elul, 1),
+            "Rosh Hashana LaBehemot",
+            Flags::MinorHoliday,
+        ),
+        // Selichot are recited from the Saturday night leaving at least four days before Rosh Hashana.
+        event(
+            (rosh_hashana - 4).on_or_before(SATURDAY),
+            "Leil Selichot",
+            Flags::MinorHoliday,
+        ),
+        event(
+            rosh_hashana - 1,
+            "Erev Rosh Hashana",
+            Flags::Erev | Flags::LightCandles,
+        ),
+    ];
+    if is_leap_year(year) {
+        events.push(event(
+            date(HebrewMonth::AdarI, 14),
+            "Purim Katan",
+            Flags::MinorHoliday,
+        ));
+        events.push(event(
+            date(HebrewMonth::AdarI, 15),
+            "Shushan Purim Katan",
+            Flags::MinorHoliday,
+        ));
+    }
+    if let Some(birkat_hachamah) = birkat_hachamah(year) {
+        events.push(event(
+            Hdate::from_ymd(year, birkat_hachamah.month, birkat_hachamah.day),
+            "Birkat Hachamah",
+            Flags::MinorHoliday,
+        ));
+    }
+    for israel in [true, false] {
+        events.extend(festival_days(
+            "Sukkot",
+            date(HebrewMonth::Tishrei, 15),
+            israel,
+            false,
+        ));
+        events.extend(festival_days(
+            "Pesach",
+            date(HebrewMonth::Nisan, 15),
+            israel,
+            true,
+        ));
+    }
+
+    events.sort_by_key(|event| event.as_ref().date);
+    events
+}
+
+/// Spellings of holiday names, mapped to the spelling of the generated titles. Both are
+/// normalized: lowercase, without spaces or punctuation.
+const TRANSLITERATIONS: [(&str, &str); 36] = [
+    ("roshhashanah", "roshhashana"),
+    ("roshhashono", "roshhashana"),
+    ("roshhashonah", "roshhashana"),
+    ("tzomgedalia", "tzomgedaliah"),
+    ("fastofgedaliah", "tzomgedaliah"),
+    ("yomkipur", "yomkippur"),
+    ("sukkos", "sukkot"),
+    ("succot", "sukkot"),
+    ("sukkoth", "sukkot"),
+    ("hoshanarabbah", "hoshanaraba"),
+    ("hoshanarabba", "hoshanaraba"),
+    ("sheminiatzeret", "shminiatzeret"),
+    ("sheminiatzeres", "shminiatzeret"),
+    ("simchastorah", "simchattorah"),
+    ("simhattorah", "simchattorah"),
+    ("hanukkah", "chanukah"),
+    ("hanukah", "chanukah"),
+    ("chanukkah", "chanukah"),
+    ("channukah", "chanukah"),
+    ("asarahbtevet", "asarabtevet"),
+    ("tubshvat", "tubishvat"),
+    ("tubishevat", "tubishvat"),
+    ("tubshevat", "tubishvat"),
+    ("fastofesther", "taanitesther"),
+    ("passover", "pesach"),
+    ("lagbomer", "lagbaomer"),
+    ("shavuos", "shavuot"),
+    ("shavuoth", "shavuot"),
+    ("tzomtamuz", "tzomtammuz"),
+    ("shivaasarbtamuz", "tzomtammuz"),
+    ("shivaasarbtammuz", "tzomtammuz"),
+    ("tishabeav", "tishabav"),
+    ("tishahbav", "tishabav"),
+    ("tubeav", "tubav"),
+    ("yomhaatzmaot", "yomhaatzmaut"),
+    ("independenceday", "yomhaatzmaut"),
+];
+
+/// Returns a holiday name normalized for comparison, with its spelling variants unified.
+fn normalize(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect();
+    TRANSLITERATIONS
+        .iter()
+        .find(|(variant, _)| *variant == name)
+        .map_or(name, |(_, title)| title.to_string())
+}
+
+/// Returns the names a holiday is known by, from the title of one of its days: "Pesach III
+/// (CH''M)" is both Pesach III and Pesach, "Sukkot VII (Hoshana Raba)" is also Sukkot and
+/// Hoshana Raba, and "Rosh Hashana 5785" is Rosh Hashana. In Israel, Shmini Atzeret is also
+/// Simchat Torah.
+fn holiday_names(event: &Event) -> Vec<&str> {
+    let description = event.description.as_str();
+    let mut name = description.strip_suffix(" (CH''M)").unwrap_or(description);
+    let mut names = vec![name];
+    if let Some((title, alias)) = name
+        .strip_suffix(')')
+        .and_then(|name| name.split_once(" ("))
+    {
+        names.push(alias);
+        name = title;
+    }
+    if let Some((title, _)) = name.split_once(": ") {
+        name = title;
+    }
+    if let Some((title, suffix)) = name.rsplit_once(' ') {
+        let is_numeral = suffix.chars().all(|c| "IVX".contains(c));
+        let is_year = suffix.chars().all(|c| c.is_ascii_digit());
+        if is_numeral || is_year {
+            name = title;
+        }
+    }
+    names.push(name);
+    if name == "Shmini Atzeret" && event.mask.contains(Flags::IsraelOnly) {
+        names.push("Simchat Torah");
+    }
+    names
+}
+
+/// Returns whether the holiday of `event` is known by `name`, in English, Hebrew or a common
+/// transliteration.
+fn is_named(event: &Event, name: &str) -> bool {
+    let name = normalize(name);
+    holiday_names(event).into_iter().any(|title| {
+        normalize(title) == name || normalize(&translate(title, Locale::Hebrew)) == name
+    })
+}
+
+/// Returns the holidays of the given Hebrew year: the days of the holidays, the fasts,
+/// Rosh Chodesh, the special Shabbatot, the modern holidays and the days of Chanukah.
+pub(crate) fn year_holidays(year: u32) -> Vec<Event> {
+    holidays_for_year(year)
+        .into_iter()
+        .chain(modern_holidays(year))
+        .chain(special_shabbatot(year))
+        .chain(rosh_chodesh(year..=year).into_iter().map(|event| event.0))
+        .map(Event::from)
+        .chain(chanukah_candles(year, None).into_iter().map(|candles| {
+            // The candles are lit on the evening before each day.
+            Event::new(
+                candles.event.date + 1,
+                "Chanukah".to_string(),
+                Flags::MinorHoliday,
+            )
+        }))
+        .collect()
+}
+
+/// Returns the days of the holiday called `name` in the given Hebrew year, in Israel or in
+/// the Diaspora, in chronological order.
+fn holiday_dates(name: &str, year: u32, israel: bool) -> Vec<Hdate> {
+    let mut dates: Vec<Hdate> = year_holidays(year)
+        .into_iter()
+        .filter(|event| {
+            if israel {
+                event.observed_in_israel()
+            } else {
+                event.observed_in_diaspora()
+            }
+        })
+        .filter(|event| is_named(event, name))
+        .map(|event| event.date)
+        .collect();
+    dates.sort();
+    dates.dedup();
+    dates
+}
+
+/// Returns the days of the holiday called `name` in the given Hebrew year, in Israel or in
+/// the Diaspora, or `None` if there is no such holiday that year.
+///
+/// The name can be given in English, in Hebrew or in a common transliteration, and matches
+/// every day of a holiday lasting several days.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{holidays, Hdate, HebrewMonth};
+///
+/// assert_eq!(
+///     holidays::find_holiday("Purim", 5790, false),
+///     Some(vec![Hdate::from_ymd(5790, HebrewMonth::AdarII, 14)])
+/// );
+/// let shavuot = holidays::find_holiday("שבועות", 5785, false).unwrap();
+/// assert_eq!(shavuot.len(), 2);
+/// assert_eq!(holidays::find_holiday("Passover", 5785, true).unwrap().len(), 7);
+/// assert_eq!(holidays::find_holiday("Purim Katan", 5785, false), None);
+/// ```
+pub fn find_holiday(name: &str, year: u32, israel: bool) -> Option<Vec<Hdate>> {
+    let dates = holiday_dates(name, year, israel);
+    (!dates.is_empty()).then_some(dates)
+}
+
+/// The number of years searched for the next occurrence of a holiday, the cycle of Birkat
+/// Hachamah.
+const OCCURRENCE_SEARCH_YEARS: u32 = 28;
+
+/// Returns the first day of the next holiday called `name` that begins on or after `from`,
+/// in Israel or in the Diaspora, or `None` if it doesn't occur in the next 28 years.
+///
+/// A holiday already under way on `from` is skipped for its next occurrence.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{holidays, Hdate, HebrewMonth};
+///
+/// let from = Hdate::from_ymd(5785, HebrewMonth::Shvat, 1);
+/// assert_eq!(
+///     holidays::next_occurrence("Chanukah", from, false),
+///     Some(Hdate::from_ymd(5786, HebrewMonth::Kislev, 25))
+/// );
+/// let from = Hdate::from_ymd(5786, HebrewMonth::Elul, 1);
+/// assert_eq!(
+///     holidays::next_occurrence("Simchat Torah", from, true),
+///     Some(Hdate::from_ymd(5787, HebrewMonth::Tishrei, 22))
+/// );
+/// ```
+pub fn next_occurrence(name: &str, from: Hdate, israel: bool) -> Option<Hdate> {
+    (from.year..=from.year + OCCURRENCE_SEARCH_YEARS).find_map(|year| {
+        let dates = holiday_dates(name, year, israel);
+        // The first days are those not following another day of the holiday.
+        dates
+            .iter()
+            .enumerate()
+            .filter(|(i, date)| *i == 0 || dates[i - 1] + 1 != **date)
+            .map(|(_, date)| *date)
+            .find(|date| *date >= from)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Flags;
+
+    #[test]
+    fn test_rosh_chodesh_dates() {
+        assert_eq!(
+            rosh_chodesh_dates(HebrewMonth::Cheshvan, 5785),
+            vec![
+                Hdate::from_ymd(5785, HebrewMonth::Tishrei, 30),
+                Hdate::from_ymd(5785, HebrewMonth::Cheshvan, 1)
+            ]
+        );
+        assert_eq!(
+            rosh_chodesh_dates(HebrewMonth::Tevet, 5784),
+            vec![Hdate::from_ymd(5784, HebrewMonth::Tevet, 1)]
+        );
+        assert_eq!(
+            rosh_chodesh_dates(HebrewMonth::Nisan, 5784),
+            vec![Hdate::from_ymd(5784, HebrewMonth::Nisan, 1)]
+        );
+        assert_eq!(
+            rosh_chodesh_dates(HebrewMonth::AdarII, 5784),
+            vec![
+                Hdate::from_ymd(5784, HebrewMonth::AdarI, 30),
+                Hdate::from_ymd(5784, HebrewMonth::AdarII, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rosh_chodesh() {
+        let events = rosh_chodesh(5784..=5785);
+        let descriptions: Vec<&str> = events
+            .iter()
+            .map(|event| event.0.as_ref().description.as_str())
+            .collect();
+        assert_eq!(
+            &descriptions[..12],
+            [
+                "Rosh Chodesh Cheshvan",
+                "Rosh Chodesh Cheshvan",
+                "Rosh Chodesh Kislev",
+                "Rosh Chodesh Tevet",
+                "Rosh Chodesh Sh'vat",
+                "Rosh Chodesh Adar I",
+                "Rosh Chodesh Adar I",
+                "Rosh Chodesh Adar II",
+                "Rosh Chodesh Adar II",
+                "Rosh Chodesh Nisan",
+                "Rosh Chodesh Iyyar",
+                "Rosh Chodesh Iyyar",
+            ]
+        );
+        assert!(descriptions.contains(&"Rosh Chodesh Adar"));
+        assert!(!descriptions.contains(&"Rosh Chodesh Tishrei"));
+        for pair in events.windows(2) {
+            assert!(pair[0].0.as_ref().date < pair[1].0.as_ref().date);
+        }
+        for event in &events {
+            let date = event.0.as_ref().date;
+            assert!(date.day == 1 || date.day == 30);
+            assert_eq!(event.0.as_ref().mask, Flags::RoshChodesh);
+        }
+        assert_eq!(
+            events.last().unwrap().0.as_ref().date,
+            Hdate::from_ymd(5785, HebrewMonth::Elul, 1)
+        );
+    }
+
+    #[test]
+    fn test_yom_kippur_katan() {
+        let events = yom_kippur_katan(5784, &YOM_KIPPUR_KATAN_OMITTED_MONTHS);
+        let descriptions: Vec<&str> = events
+            .iter()
+            .map(|event| event.as_ref().description.as_str())
+            .collect();
+        assert_eq!(
+            descriptions,
+            [
+                "Yom Kippur Katan Kislev",
+                "Yom Kippur Katan Sh'vat",
+                "Yom Kippur Katan Adar I",
+                "Yom Kippur Katan Adar II",
+                "Yom Kippur Katan Nisan",
+                "Yom Kippur Katan Sivan",
+                "Yom Kippur Katan Tamuz",
+                "Yom Kippur Katan Av",
+                "Yom Kippur Katan Elul",
+            ]
+        );
+        for event in &events {
+            let week_day = event.as_ref().date.get_week_day();
+            assert!(week_day != FRIDAY && week_day != SATURDAY);
+            assert!(event.as_ref().mask.contains(Flags::YomKippurKatan));
+        }
+        assert_eq!(
+            events[0].as_ref().date,
+            Hdate::from_ymd(5784, HebrewMonth::Cheshvan, 29)
+        );
+        // Adar I 29, 5784 is a Shabbat.
+        assert_eq!(
+            events[3].as_ref().date,
+            Hdate::from_ymd(5784, HebrewMonth::AdarI, 27)
+        );
+        // Sivan 29, 5784 is a Friday.
+        assert_eq!(
+            events[6].as_ref().date,
+            Hdate::from_ymd(5784, HebrewMonth::Sivan, 28)
+        );
+    }
+
+    #[test]
+    fn test_yom_kippur_katan_omitted_months() {
+        let events = yom_kippur_katan(5785, &[HebrewMonth::Tishrei]);
+        assert_eq!(events.len(), 11);
+        assert_eq!(events[0].as_ref().description, "Yom Kippur Katan Cheshvan");
+        assert_eq!(events[0].of_month, HebrewMonth::Cheshvan);
+        assert_eq!(yom_kippur_katan(5785, &[]).len(), 12);
+    }
+
+    fn special_shabbatot_dates(year: u32) -> Vec<(String, Hdate)> {
+        special_shabbatot(year)
+            .into_iter()
+            .map(|event| {
+                let event = event.as_ref();
+                (event.description.clone(), event.date)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_special_shabbatot() {
+        let expected = [
+            ("Shabbat Shuva", HebrewMonth::Tishrei, 3),
+            ("Shabbat Shirah", HebrewMonth::Shvat, 10),
+            // Rosh Chodesh Adar 5785 fell on Shabbat.
+            ("Shabbat Shekalim", HebrewMonth::AdarI, 1),
+            ("Shabbat Zachor", HebrewMonth::AdarI, 8),
+            ("Shabbat Parah", HebrewMonth::AdarI, 22),
+            ("Shabbat HaChodesh", HebrewMonth::AdarI, 29),
+            ("Shabbat HaGadol", HebrewMonth::Nisan, 14),
+            ("Shabbat Chazon", HebrewMonth::Av, 8),
+            ("Shabbat Nachamu", HebrewMonth::Av, 15),
+        ];
+        let expected: Vec<(String, Hdate)> = expected
+            .into_iter()
+            .map(|(name, month, day)| (name.to_string(), Hdate::from_ymd(5785, month, day)))
+            .collect();
+        assert_eq!(special_shabbatot_dates(5785), expected);
+    }
+
+    #[test]
+    fn test_special_shabbatot_leap_year() {
+        let expected = [
+            ("Shabbat Shuva", HebrewMonth::Tishrei, 8),
+            ("Shabbat Shirah", HebrewMonth::Shvat, 17),
+            ("Shabbat Shekalim", HebrewMonth::AdarI, 29),
+            ("Shabbat Zachor", HebrewMonth::AdarII, 13),
+            ("Shabbat Parah", HebrewMonth::AdarII, 20),
+            ("Shabbat HaChodesh", HebrewMonth::AdarII, 27),
+            ("Shabbat HaGadol", HebrewMonth::Nisan, 12),
+            ("Shabbat Chazon", HebrewMonth::Av, 6),
+            ("Shabbat Nachamu", HebrewMonth::Av, 13),
+        ];
+        let expected: Vec<(String, Hdate)> = expected
+            .into_iter()
+            .map(|(name, month, day)| (name.to_string(), Hdate::from_ymd(5784, month, day)))
+            .collect();
+        assert_eq!(special_shabbatot_dates(5784), expected);
+    }
+
+    #[test]
+    fn test_special_shabbatot_on_rosh_chodesh_nisan_and_tisha_bav() {
+        // Rosh Chodesh Nisan and Tish'a B'Av 5782 fell on Shabbat.
+        let shabbatot = special_shabbatot_dates(5782);
+        assert_eq!(
+            shabbatot[4],
+            (
+                "Shabbat Parah".to_string(),
+                Hdate::from_ymd(5782, HebrewMonth::AdarII, 23)
+            )
+        );
+        assert_eq!(
+            shabbatot[5],
+            (
+                "Shabbat HaChodesh".to_string(),
+                Hdate::from_ymd(5782, HebrewMonth::Nisan, 1)
+            )
+        );
+        assert_eq!(
+            shabbatot[7],
+            (
+                "Shabbat Chazon".to_string(),
+                Hdate::from_ymd(5782, HebrewMonth::Av, 9)
+            )
+        );
+        assert_eq!(
+            shabbatot[8],
+            (
+                "Shabbat Nachamu".to_string(),
+                Hdate::from_ymd(5782, HebrewMonth::Av, 16)
+            )
+        );
+    }
+
+    #[test]
+    fn test_chanukah_candles() {
+        let events = chanukah_candles(5785, None);
+        let descriptions: Vec<&str> = events
+            .iter()
+            .map(|event| event.event.description.as_str())
+            .collect();
+        assert_eq!(
+            descriptions,
+            [
+                "Chanukah: 1 Candle",
+                "Chanukah: 2 Candles",
+                "Chanukah: 3 Candles",
+                "Chanukah: 4 Candles",
+                "Chanukah: 5 Candles",
+                "Chanukah: 6 Candles",
+                "Chanukah: 7 Candles",
+                "Chanukah: 8 Candles",
+            ]
+        );
+        assert_eq!(
+            events[7].event.date,
+            Hdate::from_ymd(5785, HebrewMonth::Tevet, 1)
+        );
+        for event in &events {
+            assert_eq!(event.event.mask, Flags::ChanukahCandles);
+            assert_eq!(event.time, None);
+        }
+    }
+
+    #[test]
+    fn test_chanukah_candles_times() {
+        use chrono::{NaiveDate, Timelike};
+
+        let new_york = Location::new(40.71427, -74.00597);
+        let events = chanukah_candles(5785, Some(new_york));
+        let times: Vec<(NaiveDate, u32, u32)> = events
+            .iter()
+            .map(|event| {
+                let time = event.time.unwrap();
+                (time.date_naive(), time.hour(), time.minute())
+            })
+            .collect();
+        let date = |day| NaiveDate::from_ymd_opt(2024, 12, day).unwrap();
+        // Times in UTC; New York is 5 hours behind.
+        assert_eq!(times[0], (date(25), 22, 5)); // Wednesday, at dusk
+        assert_eq!(times[2], (date(27), 21, 17)); // Friday, before Shabbat candles
+        assert_eq!(times[3], (date(28), 22, 21)); // Saturday, after Havdalah
+    }
+
+    #[test]
+    fn test_shabbat_mevarchim_dates() {
+        let events = shabbat_mevarchim(5784, Locale::English);
+        assert_eq!(events.len(), 12);
+        for event in &events {
+            assert_eq!(event.holyday_event.as_ref().date.get_week_day(), SATURDAY);
+        }
+        assert_eq!(
+            events[0].holyday_event.as_ref().date,
+            Hdate::from_ymd(5784, HebrewMonth::Tishrei, 29)
+        );
+        assert_eq!(
+            events[0].holyday_event.as_ref().description,
+            "Shabbat Mevarchim Cheshvan"
+        );
+        assert_eq!(
+            events[5].holyday_event.as_ref().description,
+            "Shabbat Mevarchim Adar II"
+        );
+        // Sivan 30, 5784 fell on Shabbat, so Tamuz is blessed the week before.
+        assert_eq!(
+            events[9].holyday_event.as_ref().date,
+            Hdate::from_ymd(5784, HebrewMonth::Sivan, 23)
+        );
+    }
+
+    #[test]
+    fn test_shabbat_mevarchim_hebrew_memo() {
+        let events = shabbat_mevarchim(5785, Locale::Hebrew);
+        assert_eq!(
+            events[1].memo,
+            "מולד כסלו: יום ראשון, 49 דקות ו-15 חלקים אחרי 4:00. \
+             ראש חודש כסלו יהיה ביום ראשון וביום שני"
+        );
+        assert_eq!(
+            events[2].memo,
+            "מולד טבת: יום שני, 33 דקות ו-16 חלקים אחרי 17:00. \
+             ראש חודש טבת יהיה ביום שלישי וביום רביעי"
+        );
+    }
+
+    #[test]
+    fn test_modern_holidays() {
+        let date_of = |year, description: &str| {
+            modern_holidays(year)
+                .into_iter()
+                .find(|event| event.as_ref().description == description)
+                .map(|event| event.as_ref().date)
+        };
+
+        // 27 Nisan 5784 fell on a Sunday, and 27 Nisan 5785 on a Friday.
+        assert_eq!(
+            date_of(5784, "Yom HaShoah"),
+            Some(Hdate::from_ymd(5784, HebrewMonth::Nisan, 28))
+        );
+        assert_eq!(
+            date_of(5785, "Yom HaShoah"),
+            Some(Hdate::from_ymd(5785, HebrewMonth::Nisan, 26))
+        );
+
+        // Pesach 5785 fell on a Sunday, moving both days before Shabbat.
+        assert_eq!(
+            date_of(5785, "Yom HaZikaron"),
+            Some(Hdate::from_ymd(5785, HebrewMonth::Iyyar, 2))
+        );
+        assert_eq!(
+            date_of(5785, "Yom HaAtzma'ut"),
+            Some(Hdate::from_ymd(5785, HebrewMonth::Iyyar, 3))
+        );
+        // Pesach 5757 fell on a Tuesday, before the Monday postponement was enacted.
+        assert_eq!(
+            date_of(5757, "Yom HaAtzma'ut"),
+            Some(Hdate::from_ymd(5757, HebrewMonth::Iyyar, 5))
+        );
+        assert_eq!(
+            date_of(5784, "Yom HaZikaron"),
+            Some(Hdate::from_ymd(5784, HebrewMonth::Iyyar, 5))
+        );
+
+        assert_eq!(date_of(5710, "Yom HaShoah"), None);
+        assert_eq!(date_of(5776, "Yom HaAliyah"), None);
+        assert!(date_of(5777, "Yom HaAliyah").is_some());
+        assert!(modern_holidays(5700).is_empty());
+    }
+
+    #[test]
+    fn test_modern_holidays_postponement() {
+        for year in 5777..5800 {
+            let events = modern_holidays(year);
+            assert_eq!(events.len(), 12);
+            assert!(events
+                .windows(2)
+                .all(|pair| pair[0].as_ref().date <= pair[1].as_ref().date));
+            for event in &events {
+                let event = event.as_ref();
+                let week_day = event.date.get_week_day();
+                match event.description.as_str() {
+                    "Ben-Gurion Day" | "Herzl Day" | "Jabotinsky Day" => {
+                        assert_ne!(week_day, SATURDAY)
+                    }
+                    "Yitzhak Rabin Memorial Day" | "Hebrew Language Day" => {
+                        assert!(week_day != FRIDAY && week_day != SATURDAY)
+                    }
+                    "Yom HaShoah" | "Yom HaZikaron" | "Yom HaAtzma'ut" => {
+                        assert!(![FRIDAY, SATURDAY, SUNDAY].contains(&week_day))
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn descriptions(events: &[HolidayEvent], israel: bool, prefix: &str) -> Vec<String> {
+        events
+            .iter()
+            .map(|event| event.as_ref())
+            .filter(|event| {
+                if israel {
+                    event.observed_in_israel()
+                } else {
+                    event.observed_in_diaspora()
+                }
+            })
+            .filter(|event| event.mask.intersects(Flags::Chag | Flags::CholHamoed))
+            .filter(|event| event.description.starts_with(prefix))
+            .map(|event| event.description.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_holidays_for_year_israel_diaspora() {
+        let events = holidays_for_year(5785);
+        assert_eq!(
+            descriptions(&events, true, "Pesach"),
+            [
+                "Pesach I",
+                "Pesach II (CH''M)",
+                "Pesach III (CH''M)",
+                "Pesach IV (CH''M)",
+                "Pesach V (CH''M)",
+                "Pesach VI (CH''M)",
+                "Pesach VII",
+            ]
+        );
+        assert_eq!(
+            descriptions(&events, false, "Pesach"),
+            [
+                "Pesach I",
+                "Pesach II",
+                "Pesach III (CH''M)",
+                "Pesach IV (CH''M)",
+                "Pesach V (CH''M)",
+                "Pesach VI (CH''M)",
+                "Pesach VII",
+                "Pesach VIII",
+            ]
+        );
+        assert_eq!(
+            descriptions(&events, true, "Sukkot"),
+            [
+                "Sukkot I",
+                "Sukkot II (CH''M)",
+                "Sukkot III (CH''M)",
+                "Sukkot IV (CH''M)",
+                "Sukkot V (CH''M)",
+                "Sukkot VI (CH''M)",
+                "Sukkot VII (Hoshana Raba)",
+            ]
+        );
+        assert_eq!(descriptions(&events, true, "Shavuot"), ["Shavuot"]);
+        assert_eq!(
+            descriptions(&events, false, "Shavuot"),
+            ["Shavuot I", "Shavuot II"]
+        );
+        assert_eq!(descriptions(&events, true, "Simchat Torah").len(), 0);
+
+        let second_days: Vec<&str> = events
+            .iter()
+            .map(|event| event.as_ref())
+            .filter(|event| event.mask.contains(Flags::Chag | Flags::ChulOnly))
+            .map(|event| event.description.as_str())
+            .collect();
+        assert_eq!(
+            second_days,
+            [
+                "Sukkot I",
+                "Sukkot II",
+                "Shmini Atzeret",
+                "Simchat Torah",
+                "Pesach I",
+                "Pesach II",
+                "Pesach VII",
+                "Pesach VIII",
+                "Shavuot I",
+                "Shavuot II",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_holidays_for_year_erev() {
+        let events = holidays_for_year(5785);
+        let erev: Vec<(&str, Hdate)> = events
+            .iter()
+            .map(|event| event.as_ref())
+            .filter(|event| event.mask.contains(Flags::Erev | Flags::LightCandles))
+            .map(|event| (event.description.as_str(), event.date))
+            .collect();
+        assert_eq!(
+            erev,
+            [
+                (
+                    "Erev Yom Kippur",
+                    Hdate::from_ymd(5785, HebrewMonth::Tishrei, 9)
+                ),
+                (
+                    "Erev Sukkot",
+                    Hdate::from_ymd(5785, HebrewMonth::Tishrei, 14)
+                ),
+                ("Erev Pesach", Hdate::from_ymd(5785, HebrewMonth::Nisan, 14)),
+                ("Erev Shavuot", Hdate::from_ymd(5785, HebrewMonth::Sivan, 5)),
+                (
+                    "Erev Rosh Hashana",
+                    Hdate::from_ymd(5785, HebrewMonth::Elul, 29)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_holidays_for_year_fasts() {
+        let date_of = |year, description: &str| {
+            holidays_for_year(year)
+                .into_iter()
+                .find(|event| event.as_ref().description == description)
+                .map(|event| event.as_ref().date)
+        };
+        // 3 Tishrei 5785 and 9 Av 5782 fell on Shabbat.
+        assert_eq!(
+            date_of(5785, "Tzom Gedaliah"),
+            Some(Hdate::from_ymd(5785, HebrewMonth::Tishrei, 4))
+        );
+        assert_eq!(
+            date_of(5782, "Tish'a B'Av"),
+            Some(Hdate::from_ymd(5782, HebrewMonth::Av, 10))
+        );
+        assert_eq!(
+            date_of(5782, "Erev Tish'a B'Av"),
+            Some(Hdate::from_ymd(5782, HebrewMonth::Av, 9))
+        );
+        // 13 Adar II 5784 fell on Shabbat.
+        assert_eq!(
+            date_of(5784, "Ta'anit Esther"),
+            Some(Hdate::from_ymd(5784, HebrewMonth::AdarII, 11))
+        );
+        assert_eq!(
+            date_of(5784, "Purim Katan"),
+            Some(Hdate::from_ymd(5784, HebrewMonth::AdarI, 14))
+        );
+        assert_eq!(date_of(5785, "Purim Katan"), None);
+        assert_eq!(
+            date_of(5769, "Birkat Hachamah"),
+            Some(Hdate::from_ymd(5769, HebrewMonth::Nisan, 14))
+        );
+        assert_eq!(date_of(5785, "Birkat Hachamah"), None);
+    }
+
+    #[test]
+    fn test_find_holiday() {
+        let dates = |name, year, israel| {
+            find_holiday(name, year, israel)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|date| (date.month, date.day))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            dates("Rosh Hashanah", 5785, false),
+            [(HebrewMonth::Tishrei, 1), (HebrewMonth::Tishrei, 2)]
+        );
+        assert_eq!(dates("Sukkot", 5785, true).len(), 7);
+        assert_eq!(dates("Sukkot II", 5785, true), [(HebrewMonth::Tishrei, 16)]);
+        assert_eq!(dates("Sukkos", 5785, false).len(), 7);
+        assert_eq!(
+            dates("Hoshana Rabbah", 5785, false),
+            [(HebrewMonth::Tishrei, 21)]
+        );
+        assert_eq!(
+            dates("Shmini Atzeret", 5785, false),
+            [(HebrewMonth::Tishrei, 22)]
+        );
+        assert_eq!(
+            dates("Simchat Torah", 5785, true),
+            [(HebrewMonth::Tishrei, 22)]
+        );
+        assert_eq!(
+            dates("Simchas Torah", 5785, false),
+            [(HebrewMonth::Tishrei, 23)]
+        );
+        assert_eq!(dates("chanukah", 5785, false).len(), 8);
+        assert_eq!(dates("חנוכה", 5785, false)[0], (HebrewMonth::Kislev, 25));
+        assert_eq!(dates("Tisha B'Av", 5782, false), [(HebrewMonth::Av, 10)]);
+        assert_eq!(dates("ט״ו בשבט", 5785, false), [(HebrewMonth::Shvat, 15)]);
+        assert_eq!(dates("Rosh Chodesh Adar II", 5784, false).len(), 2);
+        assert_eq!(
+            dates("Shabbat Zachor", 5785, false),
+            [(HebrewMonth::AdarI, 8)]
+        );
+        assert_eq!(
+            dates("Yom Haatzmaut", 5785, true),
+            [(HebrewMonth::Iyyar, 3)]
+        );
+        assert_eq!(find_holiday("Thanksgiving", 5785, false), None);
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        let after_purim = Hdate::from_ymd(5785, HebrewMonth::AdarI, 15);
+        assert_eq!(
+            next_occurrence("Purim", after_purim, false),
+            Some(Hdate::from_ymd(5786, HebrewMonth::AdarI, 14))
+        );
+        assert_eq!(
+            next_occurrence("Purim", after_purim - 1, false),
+            Some(Hdate::from_ymd(5785, HebrewMonth::AdarI, 14))
+        );
+        assert_eq!(
+            next_occurrence("Birkat Hachamah", after_purim, false),
+            Some(Hdate::from_ymd(5797, HebrewMonth::Nisan, 23))
+        );
+        assert_eq!(next_occurrence("Thanksgiving", after_purim, false), None);
+
+        let elul = Hdate::from_ymd(5786, HebrewMonth::Elul, 1);
+        assert_eq!(
+            next_occurrence("Simchat Torah", elul, false),
+            Some(Hdate::from_ymd(5787, HebrewMonth::Tishrei, 23))
+        );
+        assert_eq!(
+            next_occurrence("Simchat Torah", elul, true),
+            Some(Hdate::from_ymd(5787, HebrewMonth::Tishrei, 22))
+        );
+        // From the middle of Chanukah, the next Chanukah begins the following year.
+        let chanukah = Hdate::from_ymd(5785, HebrewMonth::Kislev, 25);
+        assert_eq!(next_occurrence("Chanukah", chanukah, false), Some(chanukah));
+        assert_eq!(
+            next_occurrence("Chanukah", chanukah + 3, false),
+            Some(Hdate::from_ymd(5786, HebrewMonth::Kislev, 25))
+        );
+    }
+
+    #[test]
+    fn test_fast_times() {
+        let jerusalem = Location::new(31.76904, 35.21633);
+        let events = fast_times(5782, jerusalem, FastOpinions::default());
+        // Seven fasts, including Ta'anit Bechorot, each beginning and ending.
+        assert_eq!(events.len(), 14);
+        assert!(events.iter().all(|event| event.time.is_some()));
+        // 9 Av 5782 fell on Shabbat, so the fast began on its evening.
+        let tisha_bav: Vec<&TimedEvent> = events
+            .iter()
+            .filter(|event| event.holiday == "Tish'a B'Av")
+            .collect();
+        assert_eq!(
+            tisha_bav[0].event.date,
+            Hdate::from_ymd(5782, HebrewMonth::Av, 9)
+        );
+        assert_eq!(
+            tisha_bav[1].event.date,
+            Hdate::from_ymd(5782, HebrewMonth::Av, 10)
+        );
+        assert_eq!(tisha_bav[0].event.mask, Flags::MajorFast | Flags::Erev);
+        assert!(tisha_bav[1].event.mask.contains(Flags::MajorFast));
+
+        let early = fast_times(
+            5782,
+            jerusalem,
+            FastOpinions {
+                minutes_before_sunset: 18,
+                ..FastOpinions::default()
+            },
+        );
+        assert_eq!(
+            tisha_bav[0].time.unwrap() - early[12].time.unwrap(),
+            Duration::minutes(18)
+        );
+        // Minor fasts are unchanged.
+        assert_eq!(early[0], events[0]);
+    }
+}
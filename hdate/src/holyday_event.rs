@@ -1,7 +1,11 @@
+use alloc::format;
+use alloc::string::String;
+
 use chrono::NaiveDate;
 use hdate_core::hebrew::HebrewMonth;
 
-use crate::{Emoji, Event, Flags, Hdate};
+use crate::locale::{month_name, Locale};
+use crate::{CalendarEvent, Emoji, Event, Flags, Hdate};
 
 pub struct HolidayEvent(Event);
 
@@ -15,6 +19,24 @@ impl HolidayEvent {
     }
 }
 
+impl AsRef<Event> for HolidayEvent {
+    fn as_ref(&self) -> &Event {
+        &self.0
+    }
+}
+
+impl CalendarEvent for HolidayEvent {
+    fn emoji(&self) -> Option<&str> {
+        Some(self.get_emoji())
+    }
+}
+
+impl From<HolidayEvent> for Event {
+    fn from(value: HolidayEvent) -> Self {
+        value.0
+    }
+}
+
 impl Emoji for HolidayEvent {
     fn get_emoji(&self) -> &str {
         if self.0.mask.intersects(Flags::SpecialShabbat) {
@@ -28,15 +50,56 @@ impl Emoji for HolidayEvent {
 pub struct RoshChodeshEvent(pub HolidayEvent);
 
 impl RoshChodeshEvent {
+    /// Creates a Rosh Chodesh event for the given date, which is either the 1st of a month,
+    /// or the 30th day of the preceding one.
     pub fn new(date: Hdate) -> Self {
+        let month = if date.day == 30 {
+            (date + 1).month
+        } else {
+            date.month
+        };
         Self(HolidayEvent::new(
             date,
-            format!("Rosh Chodesh {}", date.month),
+            format!(
+                "Rosh Chodesh {}",
+                month_name(month, date.year, Locale::English)
+            ),
             Flags::RoshChodesh,
         ))
     }
 }
 
+impl AsRef<Event> for RoshChodeshEvent {
+    fn as_ref(&self) -> &Event {
+        self.0.as_ref()
+    }
+}
+
+impl CalendarEvent for RoshChodeshEvent {
+    fn render(&self, locale: Locale) -> String {
+        let date = self.date();
+        let month = if date.day == 30 {
+            (date + 1).month
+        } else {
+            date.month
+        };
+        match locale {
+            Locale::English => self.as_ref().description.clone(),
+            Locale::Hebrew => format!("ראש חודש {}", month_name(month, date.year, locale)),
+        }
+    }
+
+    fn emoji(&self) -> Option<&str> {
+        Some(self.get_emoji())
+    }
+}
+
+impl From<RoshChodeshEvent> for Event {
+    fn from(value: RoshChodeshEvent) -> Self {
+        value.0.into()
+    }
+}
+
 impl Emoji for RoshChodeshEvent {
     fn get_emoji(&self) -> &str {
         "🌒"
@@ -55,8 +118,71 @@ impl AsaraBTevetEvent {
     }
 }
 
+impl AsRef<Event> for AsaraBTevetEvent {
+    fn as_ref(&self) -> &Event {
+        self.0.as_ref()
+    }
+}
+
+impl CalendarEvent for AsaraBTevetEvent {
+    fn emoji(&self) -> Option<&str> {
+        Some(self.0.get_emoji())
+    }
+}
+
+pub struct YomKippurKatanEvent {
+    pub holyday_event: HolidayEvent,
+    /// The month whose Rosh Chodesh follows the fast.
+    pub of_month: HebrewMonth,
+}
+
+impl YomKippurKatanEvent {
+    /// Creates a Yom Kippur Katan event observed on `date`, ahead of Rosh Chodesh `of_month`.
+    pub fn new(date: Hdate, of_month: HebrewMonth) -> Self {
+        let holyday_event = HolidayEvent::new(
+            date,
+            format!(
+                "Yom Kippur Katan {}",
+                month_name(of_month, date.year, Locale::English)
+            ),
+            Flags::MinorFast | Flags::YomKippurKatan,
+        );
+        Self {
+            holyday_event,
+            of_month,
+        }
+    }
+}
+
+impl AsRef<Event> for YomKippurKatanEvent {
+    fn as_ref(&self) -> &Event {
+        self.holyday_event.as_ref()
+    }
+}
+
+impl CalendarEvent for YomKippurKatanEvent {
+    fn render(&self, locale: Locale) -> String {
+        let date = self.date();
+        match locale {
+            Locale::English => self.as_ref().description.clone(),
+            Locale::Hebrew => format!(
+                "יום כפור קטן {}",
+                month_name(self.of_month, date.year, locale)
+            ),
+        }
+    }
+}
+
+impl From<YomKippurKatanEvent> for Event {
+    fn from(value: YomKippurKatanEvent) -> Self {
+        value.holyday_event.into()
+    }
+}
+
 pub struct ShabbatMevarchimEvent {
     pub holyday_event: HolidayEvent,
+    /// The month whose Rosh Chodesh is announced.
+    pub of_month: HebrewMonth,
     pub memo: String,
 }
 
@@ -64,13 +190,46 @@ impl ShabbatMevarchimEvent {
     pub fn new(date: Hdate, of_month: HebrewMonth, memo: Option<String>) -> Self {
         let holyday_event = HolidayEvent::new(
             date,
-            format!("Shabbat Mevarchim {}", of_month),
+            format!(
+                "Shabbat Mevarchim {}",
+                month_name(of_month, date.year, Locale::English)
+            ),
             Flags::ShabbatMevarchim,
         );
         let memo = memo.unwrap_or_default();
         Self {
             holyday_event,
+            of_month,
             memo,
         }
     }
 }
+
+impl AsRef<Event> for ShabbatMevarchimEvent {
+    fn as_ref(&self) -> &Event {
+        self.holyday_event.as_ref()
+    }
+}
+
+impl CalendarEvent for ShabbatMevarchimEvent {
+    fn render(&self, locale: Locale) -> String {
+        let date = self.date();
+        match locale {
+            Locale::English => self.as_ref().description.clone(),
+            Locale::Hebrew => format!(
+                "שבת מברכים {}",
+                month_name(self.of_month, date.year, locale)
+            ),
+        }
+    }
+
+    fn memo(&self) -> Option<String> {
+        (!self.memo.is_empty()).then(|| self.memo.clone())
+    }
+}
+
+impl From<ShabbatMevarchimEvent> for Event {
+    fn from(value: ShabbatMevarchimEvent) -> Self {
+        value.holyday_event.into()
+    }
+}
@@ -1,16 +1,53 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm", test)))]
+compile_error!("Without the `std` feature, the `libm` feature provides the solar calculations");
+
+pub mod calendar;
+pub mod chanukah_event;
+pub mod daf_yomi_event;
+pub mod day_summary;
 pub mod event;
 pub mod hdate;
 pub mod hebrew_date_event;
+pub mod holidays;
 pub mod holyday_event;
+mod links;
+pub mod liturgy;
+pub mod locale;
+pub mod location;
+#[cfg(not(any(feature = "std", test)))]
+mod math;
 pub mod molad_event;
+pub mod parsha_event;
+pub mod reading;
+pub mod sedra;
+pub mod tekufah_event;
+pub mod timed_event;
+pub mod triennial;
+pub mod zmanim;
 
+pub use calendar::{Calendar, CalendarError};
+pub use chanukah_event::ChanukahCandlesEvent;
+pub use daf_yomi_event::DafYomiEvent;
+pub use day_summary::DaySummary;
+pub use event::CalendarEvent;
 pub use event::Event;
 pub use event::Flags;
 pub use hdate::Hdate;
-pub use hdate_core::hebrew::HebrewMonth;
+pub use hdate_core::hebrew::{HebrewMonth, HebrewYear, Tithe, YearKind};
+pub use hdate_core::tekufah;
 pub use hebrew_date_event::HebrewDateEvent;
 pub use holyday_event::HolidayEvent;
+pub use location::Location;
 pub use molad_event::MoladEvent;
+pub use parsha_event::ParshaEvent;
+pub use reading::Reading;
+pub use tekufah_event::TekufahEvent;
+pub use timed_event::TimedEvent;
+pub use zmanim::Zmanim;
 
 pub trait Emoji {
     fn get_emoji(&self) -> &str;
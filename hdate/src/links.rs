@@ -0,0 +1,231 @@
+//! Links from events to hebcal.com and Sefaria pages.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{Event, Flags};
+
+const HEBCAL_URL: &str = "https://www.hebcal.com";
+const SEFARIA_URL: &str = "https://www.sefaria.org";
+
+const ROMAN_NUMERALS: [&str; 8] = ["I", "II", "III", "IV", "V", "VI", "VII", "VIII"];
+
+/// Returns the title in lowercase, with words separated by dashes and apostrophes removed.
+pub(crate) fn slug(title: &str) -> String {
+    title
+        .replace(['\'', '’'], "")
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Returns the name of the holiday an event belongs to, without the numbering of its days,
+/// the year, the Erev prefix, or the suffix of the beginning or end of a fast.
+fn holiday_name(title: &str) -> &str {
+    let title = title.strip_prefix("Erev ").unwrap_or(title);
+    let title = title.split(" (").next().unwrap_or(title);
+    let title = title.split(':').next().unwrap_or(title);
+    let title = match title.rsplit_once(' ') {
+        Some((name, last))
+            if ROMAN_NUMERALS.contains(&last) || last.bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            name
+        }
+        _ => title,
+    };
+    title
+        .strip_suffix(" begins")
+        .or_else(|| title.strip_suffix(" ends"))
+        .unwrap_or(title)
+}
+
+/// Appends the query string selecting the Israel schedule, when needed.
+fn with_israel(url: String, israel: bool) -> String {
+    if israel {
+        url + "?i=on"
+    } else {
+        url
+    }
+}
+
+/// Returns the hebcal.com page of the holiday an event belongs to, for the Gregorian year of the event.
+pub(crate) fn holiday_url(event: &Event) -> String {
+    holiday_url_of(holiday_name(&event.description), event)
+}
+
+/// Returns the hebcal.com page of an event, derived from its flags and description.
+///
+/// Yom Kippur Katan links to its own page, Shabbat Mevarchim to the Rosh Chodesh it
+/// announces, and the weekly parsha to its page for the date it is read, on the Israel
+/// schedule when flagged [`Flags::IsraelOnly`]. The molad, the
+/// Hebrew date and the daily learning have no page.
+pub(crate) fn event_url(event: &Event) -> Option<String> {
+    let mask = event.mask;
+    if mask.intersects(Flags::Molad | Flags::HebrewDate | Flags::UserEvent) || is_learning(mask) {
+        None
+    } else if mask.intersects(Flags::ParshaHashavua) {
+        let name = event.description.strip_prefix("Parashat ")?;
+        Some(parsha_url(
+            &[name],
+            event.date.into(),
+            mask.intersects(Flags::IsraelOnly),
+        ))
+    } else if mask.intersects(Flags::YomKippurKatan) {
+        Some(holiday_url_of("Yom Kippur Katan", event))
+    } else if mask.intersects(Flags::ShabbatMevarchim) {
+        let month = event.description.strip_prefix("Shabbat Mevarchim ")?;
+        Some(holiday_url_of(&format!("Rosh Chodesh {month}"), event))
+    } else {
+        Some(holiday_url(event))
+    }
+}
+
+/// Returns whether the flags are those of a daily learning program.
+fn is_learning(mask: Flags) -> bool {
+    mask.intersects(
+        Flags::DafYomi
+            | Flags::MishnaYomi
+            | Flags::YerushalmiYomi
+            | Flags::NachYomi
+            | Flags::DailyLearning,
+    )
+}
+
+/// Returns the hebcal.com page of the given holiday, for the Gregorian year of an event.
+fn holiday_url_of(name: &str, event: &Event) -> String {
+    let year = NaiveDate::from(event.date).year();
+    with_israel(
+        format!("{HEBCAL_URL}/holidays/{}-{year}", slug(name)),
+        event.mask.intersects(Flags::IsraelOnly),
+    )
+}
+
+/// Returns the hebcal.com page of a weekly parsha read on the given date.
+pub(crate) fn parsha_url(names: &[&str], date: NaiveDate, israel: bool) -> String {
+    with_israel(
+        format!(
+            "{HEBCAL_URL}/sedrot/{}-{}",
+            slug(&names.join("-")),
+            date.format("%Y%m%d")
+        ),
+        israel,
+    )
+}
+
+/// Returns the Sefaria page of a text reference such as "Genesis 1:1-6:8" or "Berakhot 2a".
+pub(crate) fn sefaria_url(reference: &str) -> String {
+    let path = match reference.rsplit_once(' ') {
+        Some((book, location)) => {
+            format!("{}.{}", book.replace(' ', "_"), location.replace(':', "."))
+        }
+        None => reference.replace(' ', "_"),
+    };
+    format!("{SEFARIA_URL}/{path}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hdate, HebrewMonth};
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(slug("Tish'a B'Av"), "tisha-bav");
+        assert_eq!(slug("Yom HaAtzma'ut"), "yom-haatzmaut");
+        assert_eq!(slug("Lech-Lecha"), "lech-lecha");
+        assert_eq!(slug("Rosh Chodesh Adar I"), "rosh-chodesh-adar-i");
+    }
+
+    #[test]
+    fn test_holiday_url() {
+        let url = |description: &str, mask| {
+            holiday_url(&Event::new(
+                Hdate::from_ymd(5785, HebrewMonth::Nisan, 17),
+                description.to_string(),
+                mask,
+            ))
+        };
+        assert_eq!(
+            url("Pesach III (CH''M)", Flags::CholHamoed | Flags::ChulOnly),
+            "https://www.hebcal.com/holidays/pesach-2025"
+        );
+        assert_eq!(
+            url("Pesach III (CH''M)", Flags::CholHamoed | Flags::IsraelOnly),
+            "https://www.hebcal.com/holidays/pesach-2025?i=on"
+        );
+        assert_eq!(
+            url("Erev Tish'a B'Av", Flags::Erev),
+            "https://www.hebcal.com/holidays/tisha-bav-2025"
+        );
+        assert_eq!(
+            url("Chanukah: 3 Candles", Flags::ChanukahCandles),
+            "https://www.hebcal.com/holidays/chanukah-2025"
+        );
+        assert_eq!(
+            url("Rosh Hashana 5785", Flags::Chag),
+            "https://www.hebcal.com/holidays/rosh-hashana-2025"
+        );
+    }
+
+    #[test]
+    fn test_event_url() {
+        let url = |description: &str, mask| {
+            event_url(&Event::new(
+                Hdate::from_ymd(5785, HebrewMonth::Cheshvan, 27),
+                description.to_string(),
+                mask,
+            ))
+        };
+        assert_eq!(
+            url(
+                "Yom Kippur Katan Kislev",
+                Flags::MinorFast | Flags::YomKippurKatan
+            )
+            .unwrap(),
+            "https://www.hebcal.com/holidays/yom-kippur-katan-2024"
+        );
+        assert_eq!(
+            url("Shabbat Mevarchim Kislev", Flags::ShabbatMevarchim).unwrap(),
+            "https://www.hebcal.com/holidays/rosh-chodesh-kislev-2024"
+        );
+        assert_eq!(
+            url("Parashat Lech-Lecha", Flags::ParshaHashavua).unwrap(),
+            "https://www.hebcal.com/sedrot/lech-lecha-20241128"
+        );
+        assert_eq!(
+            url(
+                "Parashat Lech-Lecha",
+                Flags::ParshaHashavua | Flags::IsraelOnly
+            )
+            .unwrap(),
+            "https://www.hebcal.com/sedrot/lech-lecha-20241128?i=on"
+        );
+        assert_eq!(
+            url("Asara B'Tevet", Flags::MinorFast).unwrap(),
+            "https://www.hebcal.com/holidays/asara-btevet-2024"
+        );
+        assert_eq!(
+            url("Yom Kippur begins", Flags::MajorFast | Flags::Erev).unwrap(),
+            "https://www.hebcal.com/holidays/yom-kippur-2024"
+        );
+        assert_eq!(url("Molad Kislev 5785", Flags::Molad), None);
+        assert_eq!(url("Zevachim 6", Flags::DafYomi), None);
+    }
+
+    #[test]
+    fn test_sefaria_url() {
+        assert_eq!(
+            sefaria_url("Genesis 1:1-6:8"),
+            "https://www.sefaria.org/Genesis.1.1-6.8"
+        );
+        assert_eq!(
+            sefaria_url("Bava Kamma 2a"),
+            "https://www.sefaria.org/Bava_Kamma.2a"
+        );
+    }
+}
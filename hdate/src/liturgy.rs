@@ -0,0 +1,350 @@
+//! The additions and omissions of the daily prayers, which depend on the date.
+
+use hdate_core::hebrew::{is_leap_year, months_in_year};
+use hdate_core::tekufah::tal_umatar_start;
+
+use crate::holidays::modern_holidays;
+use crate::{Hdate, HebrewMonth};
+
+const FRIDAY: u8 = 5;
+const SATURDAY: u8 = 6;
+
+/// Whether Tachanun is said on a day, following the common Ashkenazi custom.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tachanun {
+    /// Whether Tachanun is said at Shacharit.
+    pub shacharit: bool,
+    /// Whether Tachanun is said at Mincha.
+    pub mincha: bool,
+    /// Whether all congregations follow this, or only some of them. Many congregations omit
+    /// Tachanun on Pesach Sheni, Yom HaAtzma'ut and Yom Yerushalayim.
+    pub all_congregations: bool,
+}
+
+/// The Hallel said at Shacharit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hallel {
+    None,
+    /// Half Hallel, with two passages skipped, on Rosh Chodesh and the last days of Pesach.
+    Half,
+    Whole,
+    /// The whole Hallel, said only by the congregations that celebrate Yom HaAtzma'ut and
+    /// Yom Yerushalayim.
+    Customary,
+}
+
+/// Returns the date of Tish'a B'Av in the given year, postponed to Sunday when the ninth of
+/// Av falls on Shabbat.
+fn tisha_bav(year: u32) -> Hdate {
+    let date = Hdate::from_ymd(year, HebrewMonth::Av, 9);
+    if date.get_week_day() == SATURDAY {
+        date + 1
+    } else {
+        date
+    }
+}
+
+/// Returns the modern holidays on which some congregations say Hallel and omit Tachanun.
+fn modern_festivals(year: u32) -> impl Iterator<Item = Hdate> {
+    modern_holidays(year)
+        .into_iter()
+        .filter(|event| {
+            ["Yom HaAtzma'ut", "Yom Yerushalayim"].contains(&event.as_ref().description.as_str())
+        })
+        .map(|event| event.as_ref().date)
+}
+
+impl Hdate {
+    /// Returns whether Tachanun is omitted on the whole day, by all congregations.
+    fn omits_tachanun(&self) -> bool {
+        let adar = HebrewMonth::from(months_in_year(self.year));
+        self.get_week_day() == SATURDAY
+            || self.is_rosh_chodesh()
+            || self.is_chanukah()
+            || *self == tisha_bav(self.year)
+            || match self.month {
+                HebrewMonth::Tishrei => [1, 2].contains(&self.day) || self.day >= 9,
+                HebrewMonth::Shvat => self.day == 15,
+                // Purim and Shushan Purim, and Purim Katan in a leap year.
+                HebrewMonth::AdarI if is_leap_year(self.year) => [14, 15].contains(&self.day),
+                month if month == adar => [14, 15].contains(&self.day),
+                HebrewMonth::Nisan => true,
+                HebrewMonth::Iyyar => self.day == 18,
+                // From Rosh Chodesh until the end of the days of compensation for the
+                // offerings of Shavuot.
+                HebrewMonth::Sivan => self.day <= 12,
+                HebrewMonth::Av => self.day == 15,
+                HebrewMonth::Elul => self.day == 29,
+                _ => false,
+            }
+    }
+
+    /// Returns whether Tachanun is said at Shacharit and Mincha, following the common
+    /// Ashkenazi custom.
+    ///
+    /// Tachanun is omitted on Shabbat, Rosh Chodesh, all of Nisan and Tishrei from Erev
+    /// Yom Kippur, the first twelve days of Sivan, Chanukah, Tu BiShvat, Purim, Lag BaOmer,
+    /// Tish'a B'Av, Tu B'Av and Erev Rosh Hashana, as well as at Mincha of the day before
+    /// and on Friday afternoon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::{Hdate, HebrewMonth};
+    ///
+    /// // Erev Rosh Chodesh Shvat 5785 was a Wednesday.
+    /// let tachanun = Hdate::from_ymd(5785, HebrewMonth::Tevet, 29).tachanun();
+    /// assert!(tachanun.shacharit);
+    /// assert!(!tachanun.mincha);
+    ///
+    /// let tachanun = Hdate::from_ymd(5785, HebrewMonth::Shvat, 1).tachanun();
+    /// assert!(!tachanun.shacharit);
+    /// ```
+    pub fn tachanun(&self) -> Tachanun {
+        let customary = |date: Hdate| {
+            (date.month == HebrewMonth::Iyyar && date.day == 14)
+                || modern_festivals(date.year).any(|festival| festival == date)
+        };
+        if self.omits_tachanun() {
+            return Tachanun {
+                shacharit: false,
+                mincha: false,
+                all_congregations: true,
+            };
+        }
+        let tomorrow = *self + 1;
+        Tachanun {
+            shacharit: true,
+            mincha: self.get_week_day() != FRIDAY && !tomorrow.omits_tachanun(),
+            all_congregations: !customary(*self) && !customary(tomorrow),
+        }
+    }
+
+    /// Returns the Hallel said at Shacharit.
+    ///
+    /// The whole Hallel is said on Chanukah, Sukkot and Shmini Atzeret, the first day of
+    /// Pesach and Shavuot, with their second day in the Diaspora. On Yom HaAtzma'ut and Yom
+    /// Yerushalayim, it is only said by some congregations, like the omission of Tachanun.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::liturgy::Hallel;
+    /// use hdate::{Hdate, HebrewMonth};
+    ///
+    /// assert_eq!(Hdate::from_ymd(5785, HebrewMonth::Nisan, 16).hallel(false), Hallel::Whole);
+    /// assert_eq!(Hdate::from_ymd(5785, HebrewMonth::Nisan, 16).hallel(true), Hallel::Half);
+    /// assert_eq!(Hdate::from_ymd(5785, HebrewMonth::Tishrei, 1).hallel(false), Hallel::None);
+    /// ```
+    pub fn hallel(&self, israel: bool) -> Hallel {
+        let yom_tov_days = if israel { 1 } else { 2 };
+        let whole = self.is_chanukah()
+            || match self.month {
+                HebrewMonth::Tishrei => (15..22 + yom_tov_days).contains(&self.day),
+                HebrewMonth::Nisan => (15..15 + yom_tov_days).contains(&self.day),
+                HebrewMonth::Sivan => (6..6 + yom_tov_days).contains(&self.day),
+                _ => false,
+            };
+        if whole {
+            Hallel::Whole
+        } else if self.is_rosh_chodesh()
+            || (self.month == HebrewMonth::Nisan && (15..=20 + yom_tov_days).contains(&self.day))
+        {
+            Hallel::Half
+        } else if modern_festivals(self.year).any(|festival| festival == *self) {
+            Hallel::Customary
+        } else {
+            Hallel::None
+        }
+    }
+
+    /// Returns whether Ya'aleh V'Yavo is added to the Amidah and to the Birkat HaMazon, on
+    /// Rosh Chodesh and the festivals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::{Hdate, HebrewMonth};
+    ///
+    /// assert!(Hdate::from_ymd(5785, HebrewMonth::Tishrei, 23).yaaleh_vyavo(false));
+    /// assert!(!Hdate::from_ymd(5785, HebrewMonth::Tishrei, 23).yaaleh_vyavo(true));
+    /// ```
+    pub fn yaaleh_vyavo(&self, israel: bool) -> bool {
+        let last_day = |israel_day, diaspora_day| if israel { israel_day } else { diaspora_day };
+        self.is_rosh_chodesh()
+            || match self.month {
+                HebrewMonth::Tishrei => {
+                    [1, 2, 10].contains(&self.day) || (15..=last_day(22, 23)).contains(&self.day)
+                }
+                HebrewMonth::Nisan => (15..=last_day(21, 22)).contains(&self.day),
+                HebrewMonth::Sivan => (6..=last_day(6, 7)).contains(&self.day),
+                _ => false,
+            }
+    }
+
+    /// Returns whether Al HaNissim is added to the Amidah and to the Birkat HaMazon, on
+    /// Chanukah and Purim. Walled cities such as Jerusalem say it on Shushan Purim instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::{Hdate, HebrewMonth};
+    ///
+    /// assert!(Hdate::from_ymd(5784, HebrewMonth::AdarII, 14).al_hanissim());
+    /// assert!(!Hdate::from_ymd(5784, HebrewMonth::AdarI, 14).al_hanissim());
+    /// ```
+    pub fn al_hanissim(&self) -> bool {
+        self.is_chanukah() || self.is_purim()
+    }
+
+    /// Returns whether Mashiv HaRuach is said in the Amidah of Shacharit, from Shmini
+    /// Atzeret until the first day of Pesach. On both days, the change is made at Mussaf.
+    ///
+    /// Morid HaTal is said instead during the summer in Israel and by Nusach Sefard.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::{Hdate, HebrewMonth};
+    ///
+    /// assert!(!Hdate::from_ymd(5785, HebrewMonth::Tishrei, 22).mashiv_haruach());
+    /// assert!(Hdate::from_ymd(5785, HebrewMonth::Tishrei, 23).mashiv_haruach());
+    /// assert!(Hdate::from_ymd(5785, HebrewMonth::Nisan, 15).mashiv_haruach());
+    /// assert!(!Hdate::from_ymd(5785, HebrewMonth::Nisan, 16).mashiv_haruach());
+    /// ```
+    pub fn mashiv_haruach(&self) -> bool {
+        match self.month {
+            HebrewMonth::Tishrei => self.day > 22,
+            HebrewMonth::Nisan => self.day <= 15,
+            HebrewMonth::Iyyar
+            | HebrewMonth::Sivan
+            | HebrewMonth::Tamuz
+            | HebrewMonth::Av
+            | HebrewMonth::Elul => false,
+            _ => true,
+        }
+    }
+
+    /// Returns whether V'ten Tal uMatar is said in the weekday Amidah of Shacharit, until
+    /// the first day of Pesach.
+    ///
+    /// In Israel, it is said from the seventh of Cheshvan. In the Diaspora, it is said from
+    /// Maariv of the sixtieth day from Tekufat Tishrei of Shmuel: the evening of December 4,
+    /// or of December 5 when the following Gregorian year is a leap year, between 1900
+    /// and 2099.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use hdate::Hdate;
+    ///
+    /// let date = |month, day| -> Hdate {
+    ///     NaiveDate::from_ymd_opt(2024, month, day).unwrap().try_into().unwrap()
+    /// };
+    /// assert!(!date(12, 4).tal_umatar(false));
+    /// assert!(date(12, 5).tal_umatar(false));
+    /// assert!(date(11, 8).tal_umatar(true));
+    /// ```
+    pub fn tal_umatar(&self, israel: bool) -> bool {
+        match self.month {
+            HebrewMonth::Tishrei => false,
+            HebrewMonth::Nisan => self.day < 15,
+            HebrewMonth::Iyyar
+            | HebrewMonth::Sivan
+            | HebrewMonth::Tamuz
+            | HebrewMonth::Av
+            | HebrewMonth::Elul => false,
+            HebrewMonth::Cheshvan if israel => self.day >= 7,
+            // Chanukah can end before December 5, so the start can fall in Tevet.
+            HebrewMonth::Cheshvan | HebrewMonth::Kislev | HebrewMonth::Tevet if !israel => {
+                let start = tal_umatar_start(self.year);
+                *self >= Hdate::from_ymd(start.year, start.month, start.day)
+            }
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn test_tachanun() {
+        let tachanun = |month, day| Hdate::from_ymd(5785, month, day).tachanun();
+        // Erev Yom Kippur until the end of Tishrei.
+        assert!(tachanun(HebrewMonth::Tishrei, 8).shacharit);
+        assert!(!tachanun(HebrewMonth::Tishrei, 8).mincha);
+        assert!(!tachanun(HebrewMonth::Tishrei, 9).shacharit);
+        assert!(!tachanun(HebrewMonth::Tishrei, 30).shacharit);
+        assert!(tachanun(HebrewMonth::Cheshvan, 2).shacharit);
+        // Friday afternoon.
+        assert!(!tachanun(HebrewMonth::Cheshvan, 7).mincha);
+        assert!(tachanun(HebrewMonth::Cheshvan, 7).shacharit);
+
+        assert!(!tachanun(HebrewMonth::Nisan, 20).shacharit);
+        assert!(!tachanun(HebrewMonth::Sivan, 12).shacharit);
+        assert!(tachanun(HebrewMonth::Sivan, 13).shacharit);
+        assert!(!tachanun(HebrewMonth::Iyyar, 18).shacharit);
+        // Shushan Purim Katan 5784.
+        assert!(
+            !Hdate::from_ymd(5784, HebrewMonth::AdarI, 15)
+                .tachanun()
+                .shacharit
+        );
+        assert!(
+            Hdate::from_ymd(5784, HebrewMonth::AdarI, 16)
+                .tachanun()
+                .shacharit
+        );
+
+        // Pesach Sheni is omitted by some congregations only.
+        let pesach_sheni = tachanun(HebrewMonth::Iyyar, 14);
+        assert!(pesach_sheni.shacharit);
+        assert!(!pesach_sheni.all_congregations);
+        assert!(tachanun(HebrewMonth::Iyyar, 11).all_congregations);
+    }
+
+    #[test]
+    fn test_hallel() {
+        let hallel = |month, day| Hdate::from_ymd(5785, month, day).hallel(false);
+        assert_eq!(hallel(HebrewMonth::Tishrei, 10), Hallel::None);
+        assert_eq!(hallel(HebrewMonth::Tishrei, 21), Hallel::Whole);
+        assert_eq!(hallel(HebrewMonth::Tishrei, 23), Hallel::Whole);
+        assert_eq!(hallel(HebrewMonth::Cheshvan, 1), Hallel::Half);
+        // Rosh Chodesh Tevet falls during Chanukah.
+        assert_eq!(hallel(HebrewMonth::Tevet, 1), Hallel::Whole);
+        assert_eq!(hallel(HebrewMonth::Nisan, 22), Hallel::Half);
+        assert_eq!(hallel(HebrewMonth::Nisan, 23), Hallel::None);
+        assert_eq!(hallel(HebrewMonth::Sivan, 7), Hallel::Whole);
+        assert_eq!(
+            Hdate::from_ymd(5785, HebrewMonth::Sivan, 7).hallel(true),
+            Hallel::None
+        );
+        assert_eq!(hallel(HebrewMonth::Iyyar, 28), Hallel::Customary);
+    }
+
+    #[test]
+    fn test_tal_umatar() {
+        let date = |year, month, day| -> Hdate {
+            NaiveDate::from_ymd_opt(year, month, day)
+                .unwrap()
+                .try_into()
+                .unwrap()
+        };
+        // 2024 was a leap year, so 2023 started a day later.
+        assert!(!date(2023, 12, 5).tal_umatar(false));
+        assert!(date(2023, 12, 6).tal_umatar(false));
+        assert!(date(2024, 1, 1).tal_umatar(false));
+        assert!(!date(2024, 11, 30).tal_umatar(false));
+        assert!(date(2024, 11, 30).tal_umatar(true));
+        assert!(!Hdate::from_ymd(5785, HebrewMonth::Cheshvan, 6).tal_umatar(true));
+        assert!(Hdate::from_ymd(5785, HebrewMonth::Nisan, 14).tal_umatar(false));
+        assert!(!Hdate::from_ymd(5785, HebrewMonth::Nisan, 15).tal_umatar(false));
+        assert!(!date(2100, 12, 5).tal_umatar(false));
+        assert!(date(2100, 12, 6).tal_umatar(false));
+    }
+}
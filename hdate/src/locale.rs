@@ -0,0 +1,205 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use hdate_core::hebrew::is_leap_year;
+
+use crate::HebrewMonth;
+
+/// The language in which event titles and memos are rendered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    English,
+    Hebrew,
+}
+
+const DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const HEBREW_DAY_NAMES: [&str; 7] = ["ראשון", "שני", "שלישי", "רביעי", "חמישי", "שישי", "שבת"];
+
+/// Returns the name of the given month in the given year.
+///
+/// The twelfth month is named "Adar" in a regular year, and "Adar I" in a leap year.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::HebrewMonth;
+/// use hdate::locale::{month_name, Locale};
+///
+/// assert_eq!(month_name(HebrewMonth::AdarI, 5783, Locale::English), "Adar");
+/// assert_eq!(month_name(HebrewMonth::AdarI, 5784, Locale::English), "Adar I");
+/// assert_eq!(month_name(HebrewMonth::Kislev, 5784, Locale::Hebrew), "כסלו");
+/// ```
+pub fn month_name(month: HebrewMonth, year: u32, locale: Locale) -> &'static str {
+    let leap = is_leap_year(year);
+    match locale {
+        Locale::English => match month {
+            HebrewMonth::Nisan => "Nisan",
+            HebrewMonth::Iyyar => "Iyyar",
+            HebrewMonth::Sivan => "Sivan",
+            HebrewMonth::Tamuz => "Tamuz",
+            HebrewMonth::Av => "Av",
+            HebrewMonth::Elul => "Elul",
+            HebrewMonth::Tishrei => "Tishrei",
+            HebrewMonth::Cheshvan => "Cheshvan",
+            HebrewMonth::Kislev => "Kislev",
+            HebrewMonth::Tevet => "Tevet",
+            HebrewMonth::Shvat => "Sh'vat",
+            HebrewMonth::AdarI if leap => "Adar I",
+            HebrewMonth::AdarI => "Adar",
+            HebrewMonth::AdarII => "Adar II",
+        },
+        Locale::Hebrew => match month {
+            HebrewMonth::Nisan => "ניסן",
+            HebrewMonth::Iyyar => "אייר",
+            HebrewMonth::Sivan => "סיון",
+            HebrewMonth::Tamuz => "תמוז",
+            HebrewMonth::Av => "אב",
+            HebrewMonth::Elul => "אלול",
+            HebrewMonth::Tishrei => "תשרי",
+            HebrewMonth::Cheshvan => "חשון",
+            HebrewMonth::Kislev => "כסלו",
+            HebrewMonth::Tevet => "טבת",
+            HebrewMonth::Shvat => "שבט",
+            HebrewMonth::AdarI if leap => "אדר א׳",
+            HebrewMonth::AdarI => "אדר",
+            HebrewMonth::AdarII => "אדר ב׳",
+        },
+    }
+}
+
+/// Returns the name of a day of the week, where 0 represents Sunday and 6 represents Saturday.
+///
+/// # Panics
+///
+/// This function panics if `week_day` is not between 0 and 6.
+pub fn day_name(week_day: u8, locale: Locale) -> &'static str {
+    match locale {
+        Locale::English => DAY_NAMES[week_day as usize],
+        Locale::Hebrew => HEBREW_DAY_NAMES[week_day as usize],
+    }
+}
+
+/// The Hebrew titles of the holidays, keyed by their English titles.
+const HEBREW_TITLES: [(&str, &str); 57] = [
+    ("Rosh Hashana II", "ראש השנה ב׳"),
+    ("Erev Rosh Hashana", "ערב ראש השנה"),
+    ("Tzom Gedaliah", "צום גדליה"),
+    ("Erev Yom Kippur", "ערב יום כפור"),
+    ("Yom Kippur", "יום כפור"),
+    ("Erev Sukkot", "ערב סוכות"),
+    ("Sukkot VII (Hoshana Raba)", "סוכות ז׳ (הושענא רבה)"),
+    ("Shmini Atzeret", "שמיני עצרת"),
+    ("Simchat Torah", "שמחת תורה"),
+    ("Chanukah: 8th Day", "חנוכה: יום ח׳"),
+    ("Asara B'Tevet", "עשרה בטבת"),
+    ("Tu BiShvat", "ט״ו בשבט"),
+    ("Purim Katan", "פורים קטן"),
+    ("Shushan Purim Katan", "שושן פורים קטן"),
+    ("Ta'anit Esther", "תענית אסתר"),
+    ("Erev Purim", "ערב פורים"),
+    ("Purim", "פורים"),
+    ("Shushan Purim", "שושן פורים"),
+    ("Ta'anit Bechorot", "תענית בכורות"),
+    ("Birkat Hachamah", "ברכת החמה"),
+    ("Erev Pesach", "ערב פסח"),
+    ("Pesach Sheni", "פסח שני"),
+    ("Lag BaOmer", "ל״ג בעומר"),
+    ("Erev Shavuot", "ערב שבועות"),
+    ("Shavuot", "שבועות"),
+    ("Tzom Tammuz", "צום תמוז"),
+    ("Erev Tish'a B'Av", "ערב תשעה באב"),
+    ("Tish'a B'Av", "תשעה באב"),
+    ("Tu B'Av", "ט״ו באב"),
+    ("Rosh Hashana LaBehemot", "ראש השנה לבהמות"),
+    ("Leil Selichot", "ליל סליחות"),
+    ("Yom HaShoah", "יום השואה"),
+    ("Yom HaZikaron", "יום הזיכרון"),
+    ("Yom HaAtzma'ut", "יום העצמאות"),
+    ("Yom Yerushalayim", "יום ירושלים"),
+    ("Yom HaAliyah", "יום העלייה"),
+    ("Sigd", "סיגד"),
+    ("Ben-Gurion Day", "יום בן־גוריון"),
+    ("Family Day", "יום המשפחה"),
+    ("Yitzhak Rabin Memorial Day", "יום הזיכרון ליצחק רבין"),
+    ("Herzl Day", "יום הרצל"),
+    ("Jabotinsky Day", "יום ז׳בוטינסקי"),
+    ("Hebrew Language Day", "יום השפה העברית"),
+    ("Shabbat Shuva", "שבת שובה"),
+    ("Shabbat Shirah", "שבת שירה"),
+    ("Shabbat Shekalim", "שבת שקלים"),
+    ("Shabbat Zachor", "שבת זכור"),
+    ("Shabbat Parah", "שבת פרה"),
+    ("Shabbat HaChodesh", "שבת החודש"),
+    ("Shabbat HaGadol", "שבת הגדול"),
+    ("Shabbat Chazon", "שבת חזון"),
+    ("Shabbat Nachamu", "שבת נחמו"),
+    ("Sukkot", "סוכות"),
+    ("Pesach", "פסח"),
+    ("Rosh Hashana", "ראש השנה"),
+    ("Hoshana Raba", "הושענא רבה"),
+    ("Chanukah", "חנוכה"),
+];
+
+const ROMAN_NUMERALS: [(&str, &str); 8] = [
+    ("I", "א׳"),
+    ("II", "ב׳"),
+    ("III", "ג׳"),
+    ("IV", "ד׳"),
+    ("V", "ה׳"),
+    ("VI", "ו׳"),
+    ("VII", "ז׳"),
+    ("VIII", "ח׳"),
+];
+
+/// Returns the title of a holiday in the given locale, from its English title.
+///
+/// Titles without a known translation are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::locale::{translate, Locale};
+///
+/// assert_eq!(translate("Tu BiShvat", Locale::Hebrew), "ט״ו בשבט");
+/// assert_eq!(translate("Pesach III (CH''M)", Locale::Hebrew), "פסח ג׳ (חוה״מ)");
+/// assert_eq!(translate("Rosh Hashana 5785", Locale::Hebrew), "ראש השנה 5785");
+/// assert_eq!(translate("Tu BiShvat", Locale::English), "Tu BiShvat");
+/// ```
+pub fn translate(title: &str, locale: Locale) -> String {
+    let hebrew_title = |title: &str| {
+        HEBREW_TITLES
+            .iter()
+            .find(|(english, _)| *english == title)
+            .map(|(_, hebrew)| hebrew.to_string())
+    };
+    let translated = match locale {
+        Locale::English => None,
+        Locale::Hebrew => hebrew_title(title)
+            .or_else(|| {
+                let year = title.strip_prefix("Rosh Hashana ")?;
+                year.parse::<u32>()
+                    .ok()
+                    .map(|year| format!("ראש השנה {year}"))
+            })
+            .or_else(|| {
+                let (title, chol_hamoed) = match title.strip_suffix(" (CH''M)") {
+                    Some(title) => (title, " (חוה״מ)"),
+                    None => (title, ""),
+                };
+                let (name, numeral) = title.rsplit_once(' ')?;
+                let (_, numeral) = ROMAN_NUMERALS.iter().find(|(roman, _)| *roman == numeral)?;
+                Some(format!("{} {numeral}{chol_hamoed}", hebrew_title(name)?))
+            }),
+    };
+    translated.unwrap_or_else(|| title.to_string())
+}
@@ -0,0 +1,26 @@
+/// A geographic location, used to calculate the times of the day.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::Location;
+///
+/// let jerusalem = Location::new(31.76904, 35.21633);
+/// assert_eq!(jerusalem.latitude, 31.76904);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Location {
+    /// The latitude in degrees, positive north of the equator.
+    pub latitude: f64,
+    /// The longitude in degrees, positive east of Greenwich.
+    pub longitude: f64,
+}
+
+impl Location {
+    pub fn new(latitude: f64, longitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+        }
+    }
+}
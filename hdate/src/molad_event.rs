@@ -1,8 +1,11 @@
-use std::fmt::Display;
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Display;
 
 use hdate_core::hebrew::months_in_year;
 
-use crate::{Event, Flags, Hdate, HebrewMonth};
+use crate::locale::{day_name, month_name, Locale};
+use crate::{CalendarEvent, Event, Flags, Hdate, HebrewMonth};
 
 const SHORT_DAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
 
@@ -19,13 +22,36 @@ impl MoladEvent {
     }
 }
 
+impl AsRef<Event> for MoladEvent {
+    fn as_ref(&self) -> &Event {
+        &self.event
+    }
+}
+
+impl CalendarEvent for MoladEvent {
+    fn render(&self, locale: Locale) -> String {
+        match locale {
+            Locale::English => self.event.description.clone(),
+            Locale::Hebrew => format!(
+                "מולד {} {}",
+                month_name(self.molad.month, self.molad.year, locale),
+                self.molad.year
+            ),
+        }
+    }
+
+    fn memo(&self) -> Option<String> {
+        Some(self.molad.announcement(Locale::English))
+    }
+}
+
 impl Display for MoladEvent {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.molad.fmt(f)
     }
 }
 
-struct Molad {
+pub(crate) struct Molad {
     pub year: u32,
     pub month: HebrewMonth,
     pub day_of_week: u8,
@@ -36,34 +62,30 @@ struct Molad {
 
 impl Molad {
     pub fn new(year: u32, month: HebrewMonth) -> Self {
-        let adjusted_month: f32 = month as u8 as f32 - 7.0;
-        let adjusted_month = if adjusted_month > 0.0 {
-            adjusted_month
+        let adjusted_month = if month > HebrewMonth::Tishrei {
+            u64::from(month as u8) - 7
         } else {
-            adjusted_month + months_in_year(year) as f32
+            u64::from(month as u8 + months_in_year(year)) - 7
         };
 
-        let last_year = year as f32 - 1.0;
-        let overall_months = 235.0 * (last_year / 19.0).floor();
-        let regular_months = 12.0 * (last_year % 19.0);
-        let leap_months = ((7.0 * (last_year % 19.0) + 1.0) / 19.0).floor();
+        let last_year = u64::from(year) - 1;
+        let overall_months = 235 * (last_year / 19);
+        let regular_months = 12 * (last_year % 19);
+        let leap_months = (7 * (last_year % 19) + 1) / 19;
         let elapsed_months = overall_months + regular_months + leap_months + adjusted_month;
 
-        let elapsed_parts = 204.0 + (793.0 * (elapsed_months % 1080.0)).floor();
-        let elapsed_hours = 5.0
-            + (12.0 * elapsed_months)
-            + (793.0 * (elapsed_months / 1080.0).floor())
-            + (elapsed_parts / 1080.0).floor()
-            - 6.0;
-
-        let parts = (elapsed_parts % 1080.0) + (1080.0 * (elapsed_hours % 24.0));
-        let parts = parts % 1080.0;
-        let day = 1.0 + (29.0 * elapsed_months) + (elapsed_hours / 24.0).floor();
-
-        let day_of_week = day % 7.0;
-        let hour = elapsed_hours % 24.0;
-        let minutes = (parts / 18.0).floor();
-        let parts = parts % 18.0;
+        let elapsed_parts = 204 + 793 * (elapsed_months % 1080);
+        let elapsed_hours =
+            5 + 12 * elapsed_months + 793 * (elapsed_months / 1080) + elapsed_parts / 1080 - 6;
+
+        let parts = elapsed_parts % 1080 + 1080 * (elapsed_hours % 24);
+        let parts = parts % 1080;
+        let day = 1 + 29 * elapsed_months + elapsed_hours / 24;
+
+        let day_of_week = day % 7;
+        let hour = elapsed_hours % 24;
+        let minutes = parts / 18;
+        let parts = parts % 18;
         Self {
             year,
             month,
@@ -75,13 +97,43 @@ impl Molad {
     }
 }
 
+impl Molad {
+    /// Renders the time of the molad without the year, as announced on Shabbat Mevarchim.
+    pub fn announcement(&self, locale: Locale) -> String {
+        let month = month_name(self.month, self.year, locale);
+        match locale {
+            Locale::English => format!(
+                "Molad {}: {}, {} minutes and {} chalakim after {}:00",
+                month,
+                SHORT_DAY_NAMES[self.day_of_week as usize],
+                self.minute,
+                self.parts,
+                self.hour
+            ),
+            Locale::Hebrew => format!(
+                "מולד {}: יום {}, {} דקות ו-{} חלקים אחרי {}:00",
+                month,
+                day_name(self.day_of_week, locale),
+                self.minute,
+                self.parts,
+                self.hour
+            ),
+        }
+    }
+}
+
 impl Display for Molad {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let day_name = SHORT_DAY_NAMES[self.day_of_week as usize];
         write!(
             f,
             "Molad {} {}: {}, {} minutes and {} chalakim after {}:00",
-            self.month, self.year, day_name, self.minute, self.parts, self.hour
+            month_name(self.month, self.year, Locale::English),
+            self.year,
+            day_name,
+            self.minute,
+            self.parts,
+            self.hour
         )
     }
 }
@@ -0,0 +1,114 @@
+use alloc::format;
+use alloc::string::String;
+
+use crate::links::parsha_url;
+use crate::locale::Locale;
+use crate::sedra::{PARSHIYOT, PARSHIYOT_HEBREW, PARSHIYOT_VERSES};
+use crate::{CalendarEvent, Event, Flags, Hdate};
+
+/// The weekly Torah portion read on the Shabbat of `event.date`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParshaEvent {
+    pub event: Event,
+    /// The index of the parsha in [`PARSHIYOT`].
+    pub parsha: usize,
+    /// Whether the parsha is read together with the following one.
+    pub combined: bool,
+    /// Whether the parsha is read in Israel, whose schedule differs in some years. The event
+    /// is then flagged [`Flags::IsraelOnly`], which it keeps once converted to an [`Event`].
+    pub israel: bool,
+}
+
+impl ParshaEvent {
+    pub fn new(date: Hdate, parsha: usize, combined: bool, israel: bool) -> Self {
+        let mask = if israel {
+            Flags::ParshaHashavua | Flags::IsraelOnly
+        } else {
+            Flags::ParshaHashavua
+        };
+        let mut parsha_event = Self {
+            event: Event::new(date, String::new(), mask),
+            parsha,
+            combined,
+            israel,
+        };
+        parsha_event.event.description = format!("Parashat {}", parsha_event.names().join("-"));
+        parsha_event
+    }
+
+    /// Returns the names of the parshiyot read, which are two when combined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::{Hdate, HebrewMonth, ParshaEvent};
+    ///
+    /// let parsha = ParshaEvent::new(Hdate::from_ymd(5785, HebrewMonth::Av, 1), 41, true, false);
+    /// assert_eq!(parsha.names(), ["Matot", "Masei"]);
+    /// assert_eq!(parsha.event.description, "Parashat Matot-Masei");
+    /// ```
+    pub fn names(&self) -> &'static [&'static str] {
+        let last = if self.combined {
+            self.parsha + 1
+        } else {
+            self.parsha
+        };
+        &PARSHIYOT[self.parsha..=last]
+    }
+}
+
+impl AsRef<Event> for ParshaEvent {
+    fn as_ref(&self) -> &Event {
+        &self.event
+    }
+}
+
+impl CalendarEvent for ParshaEvent {
+    fn render(&self, locale: Locale) -> String {
+        match locale {
+            Locale::English => self.event.description.clone(),
+            Locale::Hebrew => {
+                let names = &PARSHIYOT_HEBREW[self.parsha..self.parsha + self.names().len()];
+                format!("פרשת {}", names.join("-"))
+            }
+        }
+    }
+
+    /// Returns the hebcal.com page of the parsha, for the date it is read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::{CalendarEvent, Hdate, HebrewMonth, ParshaEvent};
+    ///
+    /// let parsha = ParshaEvent::new(Hdate::from_ymd(5785, HebrewMonth::Av, 1), 41, true, true);
+    /// assert_eq!(
+    ///     parsha.url().unwrap(),
+    ///     "https://www.hebcal.com/sedrot/matot-masei-20250726?i=on"
+    /// );
+    /// assert_eq!(parsha.reference().unwrap(), "Numbers 30:2-36:13");
+    /// assert_eq!(
+    ///     parsha.sefaria_url().unwrap(),
+    ///     "https://www.sefaria.org/Numbers.30.2-36.13"
+    /// );
+    /// ```
+    fn url(&self) -> Option<String> {
+        Some(parsha_url(
+            self.names(),
+            self.event.date.into(),
+            self.israel,
+        ))
+    }
+
+    fn reference(&self) -> Option<String> {
+        let (book, first_verse, _) = PARSHIYOT_VERSES[self.parsha];
+        let (_, _, last_verse) = PARSHIYOT_VERSES[self.parsha + self.names().len() - 1];
+        Some(format!("{book} {first_verse}-{last_verse}"))
+    }
+}
+
+impl From<ParshaEvent> for Event {
+    fn from(value: ParshaEvent) -> Self {
+        value.event
+    }
+}
@@ -0,0 +1,2191 @@
+//! The Torah and Haftarah readings of Shabbat, Yom Tov and weekdays.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use crate::holidays::{holidays_for_year, special_shabbatot};
+use crate::parsha_event::ParshaEvent;
+use crate::sedra::{
+    parshiyot, ACHREI_MOT, BEHAR, CHUKAT, MATOT, NITZAVIM, PARSHIYOT_VERSES, PINCHAS, TAZRIA,
+    VAYAKHEL,
+};
+use crate::{Hdate, HebrewMonth};
+
+const MONDAY: u8 = 1;
+const THURSDAY: u8 = 4;
+const SATURDAY: u8 = 6;
+
+/// A verse of a book, by chapter and number within the chapter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Verse {
+    pub chapter: u8,
+    pub verse: u8,
+}
+
+/// A range of verses of a book of the Torah, such as an aliyah.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Passage {
+    pub book: &'static str,
+    pub first: Verse,
+    pub last: Verse,
+}
+
+impl Passage {
+    /// Creates a passage from its first and last verses, written "1:1-2:3".
+    pub(crate) const fn new(book: &'static str, verses: &str) -> Self {
+        let bytes = verses.as_bytes();
+        let mut numbers = [0; 4];
+        let mut number = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i].is_ascii_digit() {
+                numbers[number] = numbers[number] * 10 + bytes[i] - b'0';
+            } else {
+                number += 1;
+            }
+            i += 1;
+        }
+        assert!(
+            number == 3,
+            "Verses are written chapter:verse-chapter:verse"
+        );
+        Self {
+            book,
+            first: Verse {
+                chapter: numbers[0],
+                verse: numbers[1],
+            },
+            last: Verse {
+                chapter: numbers[2],
+                verse: numbers[3],
+            },
+        }
+    }
+}
+
+impl Display for Passage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} {}:{}-{}:{}",
+            self.book, self.first.chapter, self.first.verse, self.last.chapter, self.last.verse
+        )
+    }
+}
+
+/// The number of verses of each chapter of the books of the Torah, against which the reading
+/// tables are checked.
+#[cfg(test)]
+const CHAPTER_VERSES: [(&str, &[u8]); 5] = [
+    (
+        "Genesis",
+        &[
+            31, 25, 24, 26, 32, 22, 24, 22, 29, 32, 32, 20, 18, 24, 21, 16, 27, 33, 38, 18, 34, 24,
+            20, 67, 34, 35, 46, 22, 35, 43, 54, 33, 20, 31, 29, 43, 36, 30, 23, 23, 57, 38, 34, 34,
+            28, 34, 31, 22, 33, 26,
+        ],
+    ),
+    (
+        "Exodus",
+        &[
+            22, 25, 22, 31, 23, 30, 29, 28, 35, 29, 10, 51, 22, 31, 27, 36, 16, 27, 25, 23, 37, 30,
+            33, 18, 40, 37, 21, 43, 46, 38, 18, 35, 23, 35, 35, 38, 29, 31, 43, 38,
+        ],
+    ),
+    (
+        "Leviticus",
+        &[
+            17, 16, 17, 35, 26, 23, 38, 36, 24, 20, 47, 8, 59, 57, 33, 34, 16, 30, 37, 27, 24, 33,
+            44, 23, 55, 46, 34,
+        ],
+    ),
+    (
+        "Numbers",
+        &[
+            54, 34, 51, 49, 31, 27, 89, 26, 23, 36, 35, 16, 33, 45, 41, 35, 28, 32, 22, 29, 35, 41,
+            30, 25, 18, 65, 23, 31, 39, 17, 54, 42, 56, 29, 34, 13,
+        ],
+    ),
+    (
+        "Deuteronomy",
+        &[
+            46, 37, 29, 49, 30, 25, 26, 20, 29, 22, 32, 31, 19, 29, 23, 22, 20, 22, 21, 20, 23, 29,
+            26, 22, 19, 19, 26, 69, 28, 20, 30, 52, 29, 12,
+        ],
+    ),
+];
+
+#[cfg(test)]
+pub(crate) fn chapters(book: &str) -> &'static [u8] {
+    CHAPTER_VERSES
+        .iter()
+        .find(|(name, _)| *name == book)
+        .map(|(_, chapters)| *chapters)
+        .expect("Readings are from the books of the Torah")
+}
+
+/// The Torah and Haftarah readings of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reading {
+    /// The passages read for each person called to the Torah, before the maftir.
+    pub aliyot: Vec<Passage>,
+    /// The maftir, read on Shabbat and Yom Tov.
+    pub maftir: Option<Passage>,
+    /// The Haftarah read by Ashkenazim, such as "Isaiah 42:5-43:10".
+    pub haftarah: Option<&'static str>,
+    /// The Haftarah read by Sephardim.
+    pub haftarah_sephardic: Option<&'static str>,
+    /// The holiday read for, or the occasion replacing the maftir or the Haftarah of
+    /// the parsha, such as "Shabbat Shekalim".
+    pub occasion: Option<&'static str>,
+}
+
+/// The regular reading of a parsha, with the verses of the book it belongs to.
+struct ParshaReading {
+    aliyot: [&'static str; 7],
+    maftir: &'static str,
+    haftarah: &'static str,
+    haftarah_sephardic: &'static str,
+}
+
+/// The readings of the parshiyot read on Shabbat, which are all but Vezot Haberakhah,
+/// read on Simchat Torah.
+const PARSHA_READINGS: [ParshaReading; 53] = [
+    ParshaReading {
+        aliyot: [
+            "1:1-2:3",
+            "2:4-2:19",
+            "2:20-3:21",
+            "3:22-4:18",
+            "4:19-4:22",
+            "4:23-5:24",
+            "5:25-6:8",
+        ],
+        maftir: "6:5-6:8",
+        haftarah: "Isaiah 42:5-43:10",
+        haftarah_sephardic: "Isaiah 42:5-21",
+    },
+    ParshaReading {
+        aliyot: [
+            "6:9-6:22",
+            "7:1-7:16",
+            "7:17-8:14",
+            "8:15-9:7",
+            "9:8-9:17",
+            "9:18-10:32",
+            "11:1-11:32",
+        ],
+        maftir: "11:29-11:32",
+        haftarah: "Isaiah 54:1-55:5",
+        haftarah_sephardic: "Isaiah 54:1-10",
+    },
+    ParshaReading {
+        aliyot: [
+            "12:1-12:13",
+            "12:14-13:4",
+            "13:5-13:18",
+            "14:1-14:20",
+            "14:21-15:6",
+            "15:7-17:6",
+            "17:7-17:27",
+        ],
+        maftir: "17:24-17:27",
+        haftarah: "Isaiah 40:27-41:16",
+        haftarah_sephardic: "Isaiah 40:27-41:16",
+    },
+    ParshaReading {
+        aliyot: [
+            "18:1-18:14",
+            "18:15-18:33",
+            "19:1-19:20",
+            "19:21-21:4",
+            "21:5-21:21",
+            "21:22-21:34",
+            "22:1-22:24",
+        ],
+        maftir: "22:20-22:24",
+        haftarah: "II Kings 4:1-37",
+        haftarah_sephardic: "II Kings 4:1-23",
+    },
+    ParshaReading {
+        aliyot: [
+            "23:1-23:16",
+            "23:17-24:9",
+            "24:10-24:26",
+            "24:27-24:52",
+            "24:53-24:67",
+            "25:1-25:11",
+            "25:12-25:18",
+        ],
+        maftir: "25:16-25:18",
+        haftarah: "I Kings 1:1-31",
+        haftarah_sephardic: "I Kings 1:1-31",
+    },
+    ParshaReading {
+        aliyot: [
+            "25:19-26:5",
+            "26:6-26:12",
+            "26:13-26:22",
+            "26:23-26:29",
+            "26:30-27:27",
+            "27:28-28:4",
+            "28:5-28:9",
+        ],
+        maftir: "28:7-28:9",
+        haftarah: "Malachi 1:1-2:7",
+        haftarah_sephardic: "Malachi 1:1-2:7",
+    },
+    ParshaReading {
+        aliyot: [
+            "28:10-28:22",
+            "29:1-29:17",
+            "29:18-30:13",
+            "30:14-30:27",
+            "30:28-31:16",
+            "31:17-31:42",
+            "31:43-32:3",
+        ],
+        maftir: "32:1-32:3",
+        haftarah: "Hosea 12:13-14:10",
+        haftarah_sephardic: "Hosea 11:7-12:12",
+    },
+    ParshaReading {
+        aliyot: [
+            "32:4-32:13",
+            "32:14-32:30",
+            "32:31-33:5",
+            "33:6-33:20",
+            "34:1-35:11",
+            "35:12-36:19",
+            "36:20-36:43",
+        ],
+        maftir: "36:40-36:43",
+        haftarah: "Obadiah 1:1-21",
+        haftarah_sephardic: "Obadiah 1:1-21",
+    },
+    ParshaReading {
+        aliyot: [
+            "37:1-37:11",
+            "37:12-37:22",
+            "37:23-37:36",
+            "38:1-38:30",
+            "39:1-39:6",
+            "39:7-39:23",
+            "40:1-40:23",
+        ],
+        maftir: "40:20-40:23",
+        haftarah: "Amos 2:6-3:8",
+        haftarah_sephardic: "Amos 2:6-3:8",
+    },
+    ParshaReading {
+        aliyot: [
+            "41:1-41:14",
+            "41:15-41:38",
+            "41:39-41:52",
+            "41:53-42:18",
+            "42:19-43:15",
+            "43:16-43:29",
+            "43:30-44:17",
+        ],
+        maftir: "44:14-44:17",
+        haftarah: "I Kings 3:15-4:1",
+        haftarah_sephardic: "I Kings 3:15-4:1",
+    },
+    ParshaReading {
+        aliyot: [
+            "44:18-44:30",
+            "44:31-45:7",
+            "45:8-45:18",
+            "45:19-45:27",
+            "45:28-46:27",
+            "46:28-47:10",
+            "47:11-47:27",
+        ],
+        maftir: "47:25-47:27",
+        haftarah: "Ezekiel 37:15-28",
+        haftarah_sephardic: "Ezekiel 37:15-28",
+    },
+    ParshaReading {
+        aliyot: [
+            "47:28-48:9",
+            "48:10-48:16",
+            "48:17-48:22",
+            "49:1-49:18",
+            "49:19-49:26",
+            "49:27-50:20",
+            "50:21-50:26",
+        ],
+        maftir: "50:23-50:26",
+        haftarah: "I Kings 2:1-12",
+        haftarah_sephardic: "I Kings 2:1-12",
+    },
+    ParshaReading {
+        aliyot: [
+            "1:1-1:17",
+            "1:18-2:10",
+            "2:11-2:25",
+            "3:1-3:15",
+            "3:16-4:17",
+            "4:18-4:31",
+            "5:1-6:1",
+        ],
+        maftir: "5:22-6:1",
+        haftarah: "Isaiah 27:6-28:13; 29:22-23",
+        haftarah_sephardic: "Jeremiah 1:1-2:3",
+    },
+    ParshaReading {
+        aliyot: [
+            "6:2-6:13",
+            "6:14-6:28",
+            "6:29-7:7",
+            "7:8-8:6",
+            "8:7-8:18",
+            "8:19-9:16",
+            "9:17-9:35",
+        ],
+        maftir: "9:33-9:35",
+        haftarah: "Ezekiel 28:25-29:21",
+        haftarah_sephardic: "Ezekiel 28:25-29:21",
+    },
+    ParshaReading {
+        aliyot: [
+            "10:1-10:11",
+            "10:12-10:23",
+            "10:24-11:3",
+            "11:4-12:20",
+            "12:21-12:28",
+            "12:29-12:51",
+            "13:1-13:16",
+        ],
+        maftir: "13:14-13:16",
+        haftarah: "Jeremiah 46:13-28",
+        haftarah_sephardic: "Jeremiah 46:13-28",
+    },
+    ParshaReading {
+        aliyot: [
+            "13:17-14:8",
+            "14:9-14:14",
+            "14:15-14:25",
+            "14:26-15:26",
+            "15:27-16:10",
+            "16:11-16:36",
+            "17:1-17:16",
+        ],
+        maftir: "17:14-17:16",
+        haftarah: "Judges 4:4-5:31",
+        haftarah_sephardic: "Judges 5:1-31",
+    },
+    ParshaReading {
+        aliyot: [
+            "18:1-18:12",
+            "18:13-18:23",
+            "18:24-18:27",
+            "19:1-19:6",
+            "19:7-19:19",
+            "19:20-20:14",
+            "20:15-20:23",
+        ],
+        maftir: "20:19-20:23",
+        haftarah: "Isaiah 6:1-7:6; 9:5-6",
+        haftarah_sephardic: "Isaiah 6:1-13",
+    },
+    ParshaReading {
+        aliyot: [
+            "21:1-21:19",
+            "21:20-22:3",
+            "22:4-22:26",
+            "22:27-23:5",
+            "23:6-23:19",
+            "23:20-23:25",
+            "23:26-24:18",
+        ],
+        maftir: "24:15-24:18",
+        haftarah: "Jeremiah 34:8-22; 33:25-26",
+        haftarah_sephardic: "Jeremiah 34:8-22; 33:25-26",
+    },
+    ParshaReading {
+        aliyot: [
+            "25:1-25:16",
+            "25:17-25:30",
+            "25:31-26:14",
+            "26:15-26:30",
+            "26:31-26:37",
+            "27:1-27:8",
+            "27:9-27:19",
+        ],
+        maftir: "27:17-27:19",
+        haftarah: "I Kings 5:26-6:13",
+        haftarah_sephardic: "I Kings 5:26-6:13",
+    },
+    ParshaReading {
+        aliyot: [
+            "27:20-28:12",
+            "28:13-28:30",
+            "28:31-28:43",
+            "29:1-29:18",
+            "29:19-29:37",
+            "29:38-29:46",
+            "30:1-30:10",
+        ],
+        maftir: "30:8-30:10",
+        haftarah: "Ezekiel 43:10-27",
+        haftarah_sephardic: "Ezekiel 43:10-27",
+    },
+    ParshaReading {
+        aliyot: [
+            "30:11-31:17",
+            "31:18-33:11",
+            "33:12-33:16",
+            "33:17-33:23",
+            "34:1-34:9",
+            "34:10-34:26",
+            "34:27-34:35",
+        ],
+        maftir: "34:33-34:35",
+        haftarah: "I Kings 18:1-39",
+        haftarah_sephardic: "I Kings 18:20-39",
+    },
+    ParshaReading {
+        aliyot: [
+            "35:1-35:20",
+            "35:21-35:29",
+            "35:30-36:7",
+            "36:8-36:19",
+            "36:20-37:16",
+            "37:17-37:29",
+            "38:1-38:20",
+        ],
+        maftir: "38:18-38:20",
+        haftarah: "I Kings 7:40-50",
+        haftarah_sephardic: "I Kings 7:13-26",
+    },
+    ParshaReading {
+        aliyot: [
+            "38:21-39:1",
+            "39:2-39:21",
+            "39:22-39:32",
+            "39:33-39:43",
+            "40:1-40:16",
+            "40:17-40:27",
+            "40:28-40:38",
+        ],
+        maftir: "40:34-40:38",
+        haftarah: "I Kings 7:51-8:21",
+        haftarah_sephardic: "I Kings 7:40-50",
+    },
+    ParshaReading {
+        aliyot: [
+            "1:1-1:13",
+            "1:14-2:6",
+            "2:7-2:16",
+            "3:1-3:17",
+            "4:1-4:26",
+            "4:27-5:10",
+            "5:11-5:26",
+        ],
+        maftir: "5:24-5:26",
+        haftarah: "Isaiah 43:21-44:23",
+        haftarah_sephardic: "Isaiah 43:21-44:23",
+    },
+    ParshaReading {
+        aliyot: [
+            "6:1-6:11",
+            "6:12-7:10",
+            "7:11-7:38",
+            "8:1-8:13",
+            "8:14-8:21",
+            "8:22-8:29",
+            "8:30-8:36",
+        ],
+        maftir: "8:33-8:36",
+        haftarah: "Jeremiah 7:21-8:3; 9:22-23",
+        haftarah_sephardic: "Jeremiah 7:21-8:3; 9:22-23",
+    },
+    ParshaReading {
+        aliyot: [
+            "9:1-9:16",
+            "9:17-9:23",
+            "9:24-10:11",
+            "10:12-10:15",
+            "10:16-10:20",
+            "11:1-11:32",
+            "11:33-11:47",
+        ],
+        maftir: "11:45-11:47",
+        haftarah: "II Samuel 6:1-7:17",
+        haftarah_sephardic: "II Samuel 6:1-19",
+    },
+    ParshaReading {
+        aliyot: [
+            "12:1-13:5",
+            "13:6-13:17",
+            "13:18-13:23",
+            "13:24-13:28",
+            "13:29-13:39",
+            "13:40-13:54",
+            "13:55-13:59",
+        ],
+        maftir: "13:56-13:59",
+        haftarah: "II Kings 4:42-5:19",
+        haftarah_sephardic: "II Kings 4:42-5:19",
+    },
+    ParshaReading {
+        aliyot: [
+            "14:1-14:12",
+            "14:13-14:20",
+            "14:21-14:32",
+            "14:33-14:53",
+            "14:54-15:15",
+            "15:16-15:28",
+            "15:29-15:33",
+        ],
+        maftir: "15:31-15:33",
+        haftarah: "II Kings 7:3-20",
+        haftarah_sephardic: "II Kings 7:3-20",
+    },
+    ParshaReading {
+        aliyot: [
+            "16:1-16:17",
+            "16:18-16:24",
+            "16:25-16:34",
+            "17:1-17:7",
+            "17:8-18:5",
+            "18:6-18:21",
+            "18:22-18:30",
+        ],
+        maftir: "18:28-18:30",
+        haftarah: "Ezekiel 22:1-19",
+        haftarah_sephardic: "Ezekiel 22:1-16",
+    },
+    ParshaReading {
+        aliyot: [
+            "19:1-19:14",
+            "19:15-19:22",
+            "19:23-19:32",
+            "19:33-19:37",
+            "20:1-20:7",
+            "20:8-20:22",
+            "20:23-20:27",
+        ],
+        maftir: "20:25-20:27",
+        haftarah: "Amos 9:7-15",
+        haftarah_sephardic: "Ezekiel 20:2-20",
+    },
+    ParshaReading {
+        aliyot: [
+            "21:1-21:15",
+            "21:16-22:16",
+            "22:17-22:33",
+            "23:1-23:22",
+            "23:23-23:32",
+            "23:33-23:44",
+            "24:1-24:23",
+        ],
+        maftir: "24:21-24:23",
+        haftarah: "Ezekiel 44:15-31",
+        haftarah_sephardic: "Ezekiel 44:15-31",
+    },
+    ParshaReading {
+        aliyot: [
+            "25:1-25:13",
+            "25:14-25:18",
+            "25:19-25:24",
+            "25:25-25:28",
+            "25:29-25:38",
+            "25:39-25:46",
+            "25:47-26:2",
+        ],
+        maftir: "25:55-26:2",
+        haftarah: "Jeremiah 32:6-27",
+        haftarah_sephardic: "Jeremiah 32:6-27",
+    },
+    ParshaReading {
+        aliyot: [
+            "26:3-26:5",
+            "26:6-26:9",
+            "26:10-26:46",
+            "27:1-27:15",
+            "27:16-27:21",
+            "27:22-27:28",
+            "27:29-27:34",
+        ],
+        maftir: "27:32-27:34",
+        haftarah: "Jeremiah 16:19-17:14",
+        haftarah_sephardic: "Jeremiah 16:19-17:14",
+    },
+    ParshaReading {
+        aliyot: [
+            "1:1-1:19",
+            "1:20-1:54",
+            "2:1-2:34",
+            "3:1-3:13",
+            "3:14-3:39",
+            "3:40-3:51",
+            "4:1-4:20",
+        ],
+        maftir: "4:17-4:20",
+        haftarah: "Hosea 2:1-22",
+        haftarah_sephardic: "Hosea 2:1-22",
+    },
+    ParshaReading {
+        aliyot: [
+            "4:21-4:37",
+            "4:38-4:49",
+            "5:1-5:10",
+            "5:11-6:27",
+            "7:1-7:41",
+            "7:42-7:71",
+            "7:72-7:89",
+        ],
+        maftir: "7:87-7:89",
+        haftarah: "Judges 13:2-25",
+        haftarah_sephardic: "Judges 13:2-25",
+    },
+    ParshaReading {
+        aliyot: [
+            "8:1-8:14",
+            "8:15-8:26",
+            "9:1-9:14",
+            "9:15-10:10",
+            "10:11-10:34",
+            "10:35-11:29",
+            "11:30-12:16",
+        ],
+        maftir: "12:14-12:16",
+        haftarah: "Zechariah 2:14-4:7",
+        haftarah_sephardic: "Zechariah 2:14-4:7",
+    },
+    ParshaReading {
+        aliyot: [
+            "13:1-13:20",
+            "13:21-14:7",
+            "14:8-14:25",
+            "14:26-15:7",
+            "15:8-15:16",
+            "15:17-15:26",
+            "15:27-15:41",
+        ],
+        maftir: "15:37-15:41",
+        haftarah: "Joshua 2:1-24",
+        haftarah_sephardic: "Joshua 2:1-24",
+    },
+    ParshaReading {
+        aliyot: [
+            "16:1-16:13",
+            "16:14-16:19",
+            "16:20-17:8",
+            "17:9-17:15",
+            "17:16-17:24",
+            "17:25-18:20",
+            "18:21-18:32",
+        ],
+        maftir: "18:30-18:32",
+        haftarah: "I Samuel 11:14-12:22",
+        haftarah_sephardic: "I Samuel 11:14-12:22",
+    },
+    ParshaReading {
+        aliyot: [
+            "19:1-19:17",
+            "19:18-20:6",
+            "20:7-20:13",
+            "20:14-20:21",
+            "20:22-21:9",
+            "21:10-21:20",
+            "21:21-22:1",
+        ],
+        maftir: "21:34-22:1",
+        haftarah: "Judges 11:1-33",
+        haftarah_sephardic: "Judges 11:1-33",
+    },
+    ParshaReading {
+        aliyot: [
+            "22:2-22:12",
+            "22:13-22:20",
+            "22:21-22:38",
+            "22:39-23:12",
+            "23:13-23:26",
+            "23:27-24:13",
+            "24:14-25:9",
+        ],
+        maftir: "25:7-25:9",
+        haftarah: "Micah 5:6-6:8",
+        haftarah_sephardic: "Micah 5:6-6:8",
+    },
+    ParshaReading {
+        aliyot: [
+            "25:10-26:4",
+            "26:5-26:51",
+            "26:52-27:5",
+            "27:6-27:23",
+            "28:1-28:15",
+            "28:16-29:11",
+            "29:12-30:1",
+        ],
+        maftir: "29:35-30:1",
+        haftarah: "I Kings 18:46-19:21",
+        haftarah_sephardic: "I Kings 18:46-19:21",
+    },
+    ParshaReading {
+        aliyot: [
+            "30:2-30:17",
+            "31:1-31:12",
+            "31:13-31:24",
+            "31:25-31:41",
+            "31:42-31:54",
+            "32:1-32:19",
+            "32:20-32:42",
+        ],
+        maftir: "32:39-32:42",
+        haftarah: "Jeremiah 1:1-2:3",
+        haftarah_sephardic: "Jeremiah 1:1-2:3",
+    },
+    ParshaReading {
+        aliyot: [
+            "33:1-33:10",
+            "33:11-33:49",
+            "33:50-34:15",
+            "34:16-34:29",
+            "35:1-35:8",
+            "35:9-35:34",
+            "36:1-36:13",
+        ],
+        maftir: "36:11-36:13",
+        haftarah: "Jeremiah 2:4-28; 3:4",
+        haftarah_sephardic: "Jeremiah 2:4-28; 4:1-2",
+    },
+    ParshaReading {
+        aliyot: [
+            "1:1-1:10",
+            "1:11-1:21",
+            "1:22-1:38",
+            "1:39-2:1",
+            "2:2-2:30",
+            "2:31-3:14",
+            "3:15-3:22",
+        ],
+        maftir: "3:20-3:22",
+        haftarah: "Isaiah 1:1-27",
+        haftarah_sephardic: "Isaiah 1:1-27",
+    },
+    ParshaReading {
+        aliyot: [
+            "3:23-4:4",
+            "4:5-4:40",
+            "4:41-4:49",
+            "5:1-5:18",
+            "5:19-6:3",
+            "6:4-6:25",
+            "7:1-7:11",
+        ],
+        maftir: "7:9-7:11",
+        haftarah: "Isaiah 40:1-26",
+        haftarah_sephardic: "Isaiah 40:1-26",
+    },
+    ParshaReading {
+        aliyot: [
+            "7:12-8:10",
+            "8:11-9:3",
+            "9:4-9:29",
+            "10:1-10:11",
+            "10:12-11:9",
+            "11:10-11:21",
+            "11:22-11:25",
+        ],
+        maftir: "11:22-11:25",
+        haftarah: "Isaiah 49:14-51:3",
+        haftarah_sephardic: "Isaiah 49:14-51:3",
+    },
+    ParshaReading {
+        aliyot: [
+            "11:26-12:10",
+            "12:11-12:28",
+            "12:29-13:19",
+            "14:1-14:21",
+            "14:22-14:29",
+            "15:1-15:18",
+            "15:19-16:17",
+        ],
+        maftir: "16:13-16:17",
+        haftarah: "Isaiah 54:11-55:5",
+        haftarah_sephardic: "Isaiah 54:11-55:5",
+    },
+    ParshaReading {
+        aliyot: [
+            "16:18-17:13",
+            "17:14-17:20",
+            "18:1-18:5",
+            "18:6-18:13",
+            "18:14-19:13",
+            "19:14-20:9",
+            "20:10-21:9",
+        ],
+        maftir: "21:7-21:9",
+        haftarah: "Isaiah 51:12-52:12",
+        haftarah_sephardic: "Isaiah 51:12-52:12",
+    },
+    ParshaReading {
+        aliyot: [
+            "21:10-21:21",
+            "21:22-22:7",
+            "22:8-23:7",
+            "23:8-23:24",
+            "23:25-24:4",
+            "24:5-24:13",
+            "24:14-25:19",
+        ],
+        maftir: "25:17-25:19",
+        haftarah: "Isaiah 54:1-10",
+        haftarah_sephardic: "Isaiah 54:1-10",
+    },
+    ParshaReading {
+        aliyot: [
+            "26:1-26:11",
+            "26:12-26:15",
+            "26:16-26:19",
+            "27:1-27:10",
+            "27:11-28:6",
+            "28:7-28:69",
+            "29:1-29:8",
+        ],
+        maftir: "29:6-29:8",
+        haftarah: "Isaiah 60:1-22",
+        haftarah_sephardic: "Isaiah 60:1-22",
+    },
+    ParshaReading {
+        aliyot: [
+            "29:9-29:11",
+            "29:12-29:14",
+            "29:15-29:28",
+            "30:1-30:6",
+            "30:7-30:10",
+            "30:11-30:14",
+            "30:15-30:20",
+        ],
+        maftir: "30:15-30:20",
+        haftarah: "Isaiah 61:10-63:9",
+        haftarah_sephardic: "Isaiah 61:10-63:9",
+    },
+    ParshaReading {
+        aliyot: [
+            "31:1-31:3",
+            "31:4-31:6",
+            "31:7-31:9",
+            "31:10-31:13",
+            "31:14-31:19",
+            "31:20-31:24",
+            "31:25-31:30",
+        ],
+        maftir: "31:28-31:30",
+        haftarah: "Isaiah 55:6-56:8",
+        haftarah_sephardic: "Isaiah 55:6-56:8",
+    },
+    ParshaReading {
+        aliyot: [
+            "32:1-32:6",
+            "32:7-32:12",
+            "32:13-32:18",
+            "32:19-32:28",
+            "32:29-32:39",
+            "32:40-32:43",
+            "32:44-32:52",
+        ],
+        maftir: "32:48-32:52",
+        haftarah: "II Samuel 22:1-51",
+        haftarah_sephardic: "II Samuel 22:1-51",
+    },
+];
+
+/// The readings of the parshiyot read together with the following one, keyed by the
+/// index of the first.
+const COMBINED_READINGS: [(usize, ParshaReading); 7] = [
+    (
+        VAYAKHEL,
+        ParshaReading {
+            aliyot: [
+                "35:1-35:20",
+                "35:21-35:29",
+                "35:30-37:16",
+                "37:17-37:29",
+                "38:1-39:1",
+                "39:2-39:21",
+                "39:22-40:38",
+            ],
+            maftir: "40:34-40:38",
+            haftarah: "I Kings 7:51-8:21",
+            haftarah_sephardic: "I Kings 7:40-50",
+        },
+    ),
+    (
+        TAZRIA,
+        ParshaReading {
+            aliyot: [
+                "12:1-13:23",
+                "13:24-13:39",
+                "13:40-13:54",
+                "13:55-14:20",
+                "14:21-14:32",
+                "14:33-15:15",
+                "15:16-15:33",
+            ],
+            maftir: "15:31-15:33",
+            haftarah: "II Kings 7:3-20",
+            haftarah_sephardic: "II Kings 7:3-20",
+        },
+    ),
+    (
+        ACHREI_MOT,
+        ParshaReading {
+            aliyot: [
+                "16:1-16:24",
+                "16:25-17:7",
+                "17:8-18:21",
+                "18:22-19:14",
+                "19:15-19:22",
+                "19:23-19:32",
+                "19:33-20:27",
+            ],
+            maftir: "20:25-20:27",
+            haftarah: "Amos 9:7-15",
+            haftarah_sephardic: "Ezekiel 20:2-20",
+        },
+    ),
+    (
+        BEHAR,
+        ParshaReading {
+            aliyot: [
+                "25:1-25:28",
+                "25:29-25:38",
+                "25:39-26:9",
+                "26:10-26:46",
+                "27:1-27:15",
+                "27:16-27:21",
+                "27:22-27:34",
+            ],
+            maftir: "27:32-27:34",
+            haftarah: "Jeremiah 16:19-17:14",
+            haftarah_sephardic: "Jeremiah 16:19-17:14",
+        },
+    ),
+    (
+        CHUKAT,
+        ParshaReading {
+            aliyot: [
+                "19:1-20:21",
+                "20:22-21:9",
+                "21:10-21:20",
+                "21:21-22:12",
+                "22:13-22:38",
+                "22:39-23:26",
+                "23:27-25:9",
+            ],
+            maftir: "25:7-25:9",
+            haftarah: "Micah 5:6-6:8",
+            haftarah_sephardic: "Micah 5:6-6:8",
+        },
+    ),
+    (
+        MATOT,
+        ParshaReading {
+            aliyot: [
+                "30:2-31:12",
+                "31:13-31:54",
+                "32:1-32:19",
+                "32:20-33:49",
+                "33:50-34:15",
+                "34:16-35:8",
+                "35:9-36:13",
+            ],
+            maftir: "36:11-36:13",
+            haftarah: "Jeremiah 2:4-28; 3:4",
+            haftarah_sephardic: "Jeremiah 2:4-28; 4:1-2",
+        },
+    ),
+    (
+        NITZAVIM,
+        ParshaReading {
+            aliyot: [
+                "29:9-29:28",
+                "30:1-30:6",
+                "30:7-30:14",
+                "30:15-31:6",
+                "31:7-31:13",
+                "31:14-31:19",
+                "31:20-31:30",
+            ],
+            maftir: "31:28-31:30",
+            haftarah: "Isaiah 61:10-63:9",
+            haftarah_sephardic: "Isaiah 61:10-63:9",
+        },
+    ),
+];
+
+/// A maftir or Haftarah replacing the one of the parsha on a special Shabbat.
+struct Substitution {
+    occasion: &'static str,
+    maftir: Option<Passage>,
+    haftarah: &'static str,
+    haftarah_sephardic: &'static str,
+}
+
+const SPECIAL_SHABBAT_READINGS: [Substitution; 6] = [
+    Substitution {
+        occasion: "Shabbat Shuva",
+        maftir: None,
+        haftarah: "Hosea 14:2-10; Joel 2:15-27",
+        haftarah_sephardic: "Hosea 14:2-10; Micah 7:18-20",
+    },
+    Substitution {
+        occasion: "Shabbat Shekalim",
+        maftir: Some(Passage::new("Exodus", "30:11-30:16")),
+        haftarah: "II Kings 12:1-17",
+        haftarah_sephardic: "II Kings 11:17-12:17",
+    },
+    Substitution {
+        occasion: "Shabbat Zachor",
+        maftir: Some(Passage::new("Deuteronomy", "25:17-25:19")),
+        haftarah: "I Samuel 15:2-34",
+        haftarah_sephardic: "I Samuel 15:1-34",
+    },
+    Substitution {
+        occasion: "Shabbat Parah",
+        maftir: Some(Passage::new("Numbers", "19:1-19:22")),
+        haftarah: "Ezekiel 36:16-38",
+        haftarah_sephardic: "Ezekiel 36:16-36",
+    },
+    Substitution {
+        occasion: "Shabbat HaChodesh",
+        maftir: Some(Passage::new("Exodus", "12:1-12:20")),
+        haftarah: "Ezekiel 45:16-46:18",
+        haftarah_sephardic: "Ezekiel 45:18-46:15",
+    },
+    Substitution {
+        occasion: "Shabbat HaGadol",
+        maftir: None,
+        haftarah: "Malachi 3:4-24",
+        haftarah_sephardic: "Malachi 3:4-24",
+    },
+];
+
+const ROSH_CHODESH_READING: Substitution = Substitution {
+    occasion: "Shabbat Rosh Chodesh",
+    maftir: Some(Passage::new("Numbers", "28:9-28:15")),
+    haftarah: "Isaiah 66:1-24",
+    haftarah_sephardic: "Isaiah 66:1-24",
+};
+
+const MACHAR_CHODESH_READING: Substitution = Substitution {
+    occasion: "Shabbat Machar Chodesh",
+    maftir: None,
+    haftarah: "I Samuel 20:18-42",
+    haftarah_sephardic: "I Samuel 20:18-42",
+};
+
+/// The maftir of each day of Chanukah, read from the offerings of the princes.
+const CHANUKAH_MAFTIR: [&str; 8] = [
+    "7:1-7:17",
+    "7:18-7:23",
+    "7:24-7:29",
+    "7:30-7:35",
+    "7:36-7:41",
+    "7:42-7:47",
+    "7:48-7:53",
+    "7:54-8:4",
+];
+
+/// The aliyot of each weekday of Chanukah. The third aliyah reads the offering of the
+/// following day.
+const CHANUKAH_ALIYOT: [[&str; 3]; 8] = [
+    ["7:1-7:11", "7:12-7:14", "7:15-7:17"],
+    ["7:18-7:20", "7:21-7:23", "7:24-7:29"],
+    ["7:24-7:26", "7:27-7:29", "7:30-7:35"],
+    ["7:30-7:32", "7:33-7:35", "7:36-7:41"],
+    ["7:36-7:38", "7:39-7:41", "7:42-7:47"],
+    ["7:42-7:44", "7:45-7:47", "7:48-7:53"],
+    ["7:48-7:50", "7:51-7:53", "7:54-7:59"],
+    ["7:54-7:56", "7:57-7:59", "7:60-8:4"],
+];
+
+const ROSH_CHODESH_ALIYOT: [Passage; 4] = [
+    Passage::new("Numbers", "28:1-28:3"),
+    Passage::new("Numbers", "28:3-28:5"),
+    Passage::new("Numbers", "28:6-28:10"),
+    Passage::new("Numbers", "28:11-28:15"),
+];
+
+const ROSH_CHODESH_ON_CHANUKAH_ALIYOT: [Passage; 3] = [
+    Passage::new("Numbers", "28:1-28:5"),
+    Passage::new("Numbers", "28:6-28:10"),
+    Passage::new("Numbers", "28:11-28:15"),
+];
+
+/// The fasts with a Torah reading, as named by [`holidays_for_year`].
+const FASTS: [&str; 5] = [
+    "Tzom Gedaliah",
+    "Asara B'Tevet",
+    "Ta'anit Esther",
+    "Tzom Tammuz",
+    TISHA_BAV,
+];
+const TISHA_BAV: &str = "Tish'a B'Av";
+
+/// Vayechal, read on the fasts in the morning and at Mincha.
+const VAYECHAL_ALIYOT: [Passage; 3] = [
+    Passage::new("Exodus", "32:11-32:14"),
+    Passage::new("Exodus", "34:1-34:3"),
+    Passage::new("Exodus", "34:4-34:10"),
+];
+
+/// The Haftarah read by Ashkenazim at Mincha on the fasts.
+const FAST_DAY_HAFTARAH: &str = "Isaiah 55:6-56:8";
+
+const TISHA_BAV_ALIYOT: [Passage; 3] = [
+    Passage::new("Deuteronomy", "4:25-4:29"),
+    Passage::new("Deuteronomy", "4:30-4:35"),
+    Passage::new("Deuteronomy", "4:36-4:40"),
+];
+
+/// The Haftarah read in the morning of Tish'a B'Av.
+const TISHA_BAV_HAFTARAH: &str = "Jeremiah 8:13-9:23";
+
+const PURIM_ALIYOT: [Passage; 3] = [
+    Passage::new("Exodus", "17:8-17:10"),
+    Passage::new("Exodus", "17:11-17:13"),
+    Passage::new("Exodus", "17:14-17:16"),
+];
+
+/// The aliyot read on Monday and Thursday from each parsha, with the verses of the book it
+/// belongs to.
+const WEEKDAY_ALIYOT: [[&str; 3]; 54] = [
+    ["1:1-1:5", "1:6-1:8", "1:9-1:13"],
+    ["6:9-6:16", "6:17-6:19", "6:20-6:22"],
+    ["12:1-12:3", "12:4-12:9", "12:10-12:13"],
+    ["18:1-18:5", "18:6-18:8", "18:9-18:14"],
+    ["23:1-23:4", "23:5-23:7", "23:8-23:16"],
+    ["25:19-25:22", "25:23-25:26", "25:27-26:5"],
+    ["28:10-28:12", "28:13-28:17", "28:18-28:22"],
+    ["32:4-32:6", "32:7-32:9", "32:10-32:13"],
+    ["37:1-37:3", "37:4-37:7", "37:8-37:11"],
+    ["41:1-41:4", "41:5-41:7", "41:8-41:14"],
+    ["44:18-44:20", "44:21-44:24", "44:25-44:30"],
+    ["47:28-47:31", "48:1-48:3", "48:4-48:9"],
+    ["1:1-1:7", "1:8-1:12", "1:13-1:17"],
+    ["6:2-6:5", "6:6-6:9", "6:10-6:13"],
+    ["10:1-10:3", "10:4-10:6", "10:7-10:11"],
+    ["13:17-13:22", "14:1-14:4", "14:5-14:8"],
+    ["18:1-18:4", "18:5-18:8", "18:9-18:12"],
+    ["21:1-21:3", "21:4-21:6", "21:7-21:11"],
+    ["25:1-25:5", "25:6-25:9", "25:10-25:16"],
+    ["27:20-27:21", "28:1-28:5", "28:6-28:12"],
+    ["30:11-30:13", "30:14-30:16", "30:17-30:21"],
+    ["35:1-35:10", "35:11-35:20", "35:21-35:29"],
+    ["38:21-38:23", "38:24-38:27", "38:28-39:1"],
+    ["1:1-1:4", "1:5-1:9", "1:10-1:13"],
+    ["6:1-6:3", "6:4-6:6", "6:7-6:11"],
+    ["9:1-9:6", "9:7-9:10", "9:11-9:16"],
+    ["12:1-12:4", "12:5-12:8", "13:1-13:5"],
+    ["14:1-14:5", "14:6-14:9", "14:10-14:12"],
+    ["16:1-16:6", "16:7-16:11", "16:12-16:17"],
+    ["19:1-19:4", "19:5-19:10", "19:11-19:14"],
+    ["21:1-21:6", "21:7-21:12", "21:13-21:15"],
+    ["25:1-25:3", "25:4-25:7", "25:8-25:13"],
+    ["26:3-26:5", "26:6-26:9", "26:10-26:13"],
+    ["1:1-1:4", "1:5-1:16", "1:17-1:19"],
+    ["4:21-4:24", "4:25-4:28", "4:29-4:37"],
+    ["8:1-8:4", "8:5-8:9", "8:10-8:14"],
+    ["13:1-13:3", "13:4-13:16", "13:17-13:20"],
+    ["16:1-16:3", "16:4-16:7", "16:8-16:13"],
+    ["19:1-19:6", "19:7-19:9", "19:10-19:17"],
+    ["22:2-22:4", "22:5-22:7", "22:8-22:12"],
+    ["25:10-25:12", "25:13-25:15", "25:16-26:4"],
+    ["30:2-30:9", "30:10-30:13", "30:14-30:17"],
+    ["33:1-33:3", "33:4-33:6", "33:7-33:10"],
+    ["1:1-1:3", "1:4-1:7", "1:8-1:11"],
+    ["3:23-3:25", "3:26-4:4", "4:5-4:8"],
+    ["7:12-7:21", "7:22-8:3", "8:4-8:10"],
+    ["11:26-11:31", "11:32-12:5", "12:6-12:10"],
+    ["16:18-16:20", "16:21-17:10", "17:11-17:13"],
+    ["21:10-21:14", "21:15-21:17", "21:18-21:21"],
+    ["26:1-26:3", "26:4-26:11", "26:12-26:15"],
+    ["29:9-29:11", "29:12-29:14", "29:15-29:28"],
+    ["31:1-31:3", "31:4-31:6", "31:7-31:13"],
+    ["32:1-32:3", "32:4-32:6", "32:7-32:12"],
+    ["33:1-33:7", "33:8-33:12", "33:13-33:17"],
+];
+
+/// The Mon/Thu readings go from Vezot Haberakhah after Yom Kippur, until it is read on
+/// Simchat Torah.
+const VEZOT_HABERAKHAH: usize = 53;
+
+/// The readings of the weekdays of Chol HaMoed Pesach, in the order they are read. The
+/// first is only read in Israel, where the second day of Pesach is Chol HaMoed.
+const PESACH_CHOL_HAMOED_ALIYOT: [[Passage; 3]; 5] = [
+    [
+        Passage::new("Leviticus", "22:26-23:3"),
+        Passage::new("Leviticus", "23:4-23:14"),
+        Passage::new("Leviticus", "23:15-23:44"),
+    ],
+    [
+        Passage::new("Exodus", "13:1-13:4"),
+        Passage::new("Exodus", "13:5-13:10"),
+        Passage::new("Exodus", "13:11-13:16"),
+    ],
+    [
+        Passage::new("Exodus", "22:24-22:26"),
+        Passage::new("Exodus", "22:27-23:5"),
+        Passage::new("Exodus", "23:6-23:19"),
+    ],
+    PESAL_LECHA_ALIYOT,
+    [
+        Passage::new("Numbers", "9:1-9:5"),
+        Passage::new("Numbers", "9:6-9:8"),
+        Passage::new("Numbers", "9:9-9:14"),
+    ],
+];
+
+/// Pesal Lecha, which is read on Shabbat Chol HaMoed when there is one.
+const PESAL_LECHA_ALIYOT: [Passage; 3] = [
+    Passage::new("Exodus", "34:1-34:3"),
+    Passage::new("Exodus", "34:4-34:17"),
+    Passage::new("Exodus", "34:18-34:26"),
+];
+
+/// The reading of a day of Yom Tov.
+#[derive(Clone, Copy)]
+struct HolidayReading {
+    holiday: &'static str,
+    aliyot: &'static [Passage],
+    maftir: Passage,
+    haftarah: &'static str,
+    haftarah_sephardic: &'static str,
+}
+
+const SUKKOT_ALIYOT: [Passage; 5] = [
+    Passage::new("Leviticus", "22:26-22:33"),
+    Passage::new("Leviticus", "23:1-23:3"),
+    Passage::new("Leviticus", "23:4-23:14"),
+    Passage::new("Leviticus", "23:15-23:22"),
+    Passage::new("Leviticus", "23:23-23:44"),
+];
+
+const KOL_HABECHOR_ALIYOT: [Passage; 5] = [
+    Passage::new("Deuteronomy", "15:19-15:23"),
+    Passage::new("Deuteronomy", "16:1-16:3"),
+    Passage::new("Deuteronomy", "16:4-16:8"),
+    Passage::new("Deuteronomy", "16:9-16:12"),
+    Passage::new("Deuteronomy", "16:13-16:17"),
+];
+
+/// The offerings of each day of Sukkot, read as the maftir of Chol HaMoed.
+const SUKKOT_OFFERINGS: [Passage; 7] = [
+    Passage::new("Numbers", "29:12-29:16"),
+    Passage::new("Numbers", "29:17-29:19"),
+    Passage::new("Numbers", "29:20-29:22"),
+    Passage::new("Numbers", "29:23-29:25"),
+    Passage::new("Numbers", "29:26-29:28"),
+    Passage::new("Numbers", "29:29-29:31"),
+    Passage::new("Numbers", "29:32-29:34"),
+];
+
+const SHABBAT_CHOL_HAMOED_ALIYOT: [Passage; 7] = [
+    Passage::new("Exodus", "33:12-33:16"),
+    Passage::new("Exodus", "33:17-33:19"),
+    Passage::new("Exodus", "33:20-33:23"),
+    Passage::new("Exodus", "34:1-34:3"),
+    Passage::new("Exodus", "34:4-34:10"),
+    Passage::new("Exodus", "34:11-34:17"),
+    Passage::new("Exodus", "34:18-34:26"),
+];
+
+const ROSH_HASHANA_MAFTIR: Passage = Passage::new("Numbers", "29:1-29:6");
+const SUKKOT_MAFTIR: Passage = Passage::new("Numbers", "29:12-29:16");
+const SHMINI_ATZERET_MAFTIR: Passage = Passage::new("Numbers", "29:35-30:1");
+const PESACH_MAFTIR: Passage = Passage::new("Numbers", "28:16-28:25");
+const LAST_DAYS_OF_PESACH_MAFTIR: Passage = Passage::new("Numbers", "28:19-28:25");
+const SHAVUOT_MAFTIR: Passage = Passage::new("Numbers", "28:26-28:31");
+
+const ROSH_HASHANA_I: HolidayReading = HolidayReading {
+    holiday: "Rosh Hashana I",
+    aliyot: &[
+        Passage::new("Genesis", "21:1-21:4"),
+        Passage::new("Genesis", "21:5-21:12"),
+        Passage::new("Genesis", "21:13-21:17"),
+        Passage::new("Genesis", "21:18-21:21"),
+        Passage::new("Genesis", "21:22-21:34"),
+    ],
+    maftir: ROSH_HASHANA_MAFTIR,
+    haftarah: "I Samuel 1:1-2:10",
+    haftarah_sephardic: "I Samuel 1:1-2:10",
+};
+
+const ROSH_HASHANA_II: HolidayReading = HolidayReading {
+    holiday: "Rosh Hashana II",
+    aliyot: &[
+        Passage::new("Genesis", "22:1-22:3"),
+        Passage::new("Genesis", "22:4-22:8"),
+        Passage::new("Genesis", "22:9-22:14"),
+        Passage::new("Genesis", "22:15-22:19"),
+        Passage::new("Genesis", "22:20-22:24"),
+    ],
+    maftir: ROSH_HASHANA_MAFTIR,
+    haftarah: "Jeremiah 31:1-19",
+    haftarah_sephardic: "Jeremiah 31:1-19",
+};
+
+const YOM_KIPPUR: HolidayReading = HolidayReading {
+    holiday: "Yom Kippur",
+    aliyot: &[
+        Passage::new("Leviticus", "16:1-16:6"),
+        Passage::new("Leviticus", "16:7-16:11"),
+        Passage::new("Leviticus", "16:12-16:17"),
+        Passage::new("Leviticus", "16:18-16:24"),
+        Passage::new("Leviticus", "16:25-16:30"),
+        Passage::new("Leviticus", "16:31-16:34"),
+    ],
+    maftir: Passage::new("Numbers", "29:7-29:11"),
+    haftarah: "Isaiah 57:14-58:14",
+    haftarah_sephardic: "Isaiah 57:14-58:14",
+};
+
+const SUKKOT_I: HolidayReading = HolidayReading {
+    holiday: "Sukkot I",
+    aliyot: &SUKKOT_ALIYOT,
+    maftir: SUKKOT_MAFTIR,
+    haftarah: "Zechariah 14:1-21",
+    haftarah_sephardic: "Zechariah 14:1-21",
+};
+
+const SUKKOT_II: HolidayReading = HolidayReading {
+    holiday: "Sukkot II",
+    aliyot: &SUKKOT_ALIYOT,
+    maftir: SUKKOT_MAFTIR,
+    haftarah: "I Kings 8:2-21",
+    haftarah_sephardic: "I Kings 8:2-21",
+};
+
+const SHMINI_ATZERET: HolidayReading = HolidayReading {
+    holiday: "Shmini Atzeret",
+    aliyot: &[
+        Passage::new("Deuteronomy", "14:22-14:29"),
+        Passage::new("Deuteronomy", "15:1-15:18"),
+        Passage::new("Deuteronomy", "15:19-15:23"),
+        Passage::new("Deuteronomy", "16:1-16:3"),
+        Passage::new("Deuteronomy", "16:4-16:17"),
+    ],
+    maftir: SHMINI_ATZERET_MAFTIR,
+    haftarah: "I Kings 8:54-66",
+    haftarah_sephardic: "I Kings 8:54-66",
+};
+
+/// Vezot Haberakhah is completed and Bereshit started again, on Shmini Atzeret in Israel.
+const SIMCHAT_TORAH: HolidayReading = HolidayReading {
+    holiday: "Simchat Torah",
+    aliyot: &[
+        Passage::new("Deuteronomy", "33:1-33:7"),
+        Passage::new("Deuteronomy", "33:8-33:12"),
+        Passage::new("Deuteronomy", "33:13-33:17"),
+        Passage::new("Deuteronomy", "33:18-33:21"),
+        Passage::new("Deuteronomy", "33:22-33:26"),
+        Passage::new("Deuteronomy", "33:27-34:12"),
+        Passage::new("Genesis", "1:1-2:3"),
+    ],
+    maftir: SHMINI_ATZERET_MAFTIR,
+    haftarah: "Joshua 1:1-18",
+    haftarah_sephardic: "Joshua 1:1-9",
+};
+
+const PESACH_I: HolidayReading = HolidayReading {
+    holiday: "Pesach I",
+    aliyot: &[
+        Passage::new("Exodus", "12:21-12:24"),
+        Passage::new("Exodus", "12:25-12:28"),
+        Passage::new("Exodus", "12:29-12:36"),
+        Passage::new("Exodus", "12:37-12:42"),
+        Passage::new("Exodus", "12:43-12:51"),
+    ],
+    maftir: PESACH_MAFTIR,
+    haftarah: "Joshua 3:5-7; 5:2-6:1; 6:27",
+    haftarah_sephardic: "Joshua 5:2-6:1",
+};
+
+const PESACH_II: HolidayReading = HolidayReading {
+    holiday: "Pesach II",
+    aliyot: &SUKKOT_ALIYOT,
+    maftir: PESACH_MAFTIR,
+    haftarah: "II Kings 23:1-9; 23:21-25",
+    haftarah_sephardic: "II Kings 23:1-9; 23:21-25",
+};
+
+const PESACH_VII: HolidayReading = HolidayReading {
+    holiday: "Pesach VII",
+    aliyot: &[
+        Passage::new("Exodus", "13:17-13:22"),
+        Passage::new("Exodus", "14:1-14:8"),
+        Passage::new("Exodus", "14:9-14:14"),
+        Passage::new("Exodus", "14:15-14:25"),
+        Passage::new("Exodus", "14:26-15:26"),
+    ],
+    maftir: LAST_DAYS_OF_PESACH_MAFTIR,
+    haftarah: "II Samuel 22:1-51",
+    haftarah_sephardic: "II Samuel 22:1-51",
+};
+
+const PESACH_VIII: HolidayReading = HolidayReading {
+    holiday: "Pesach VIII",
+    aliyot: &KOL_HABECHOR_ALIYOT,
+    maftir: LAST_DAYS_OF_PESACH_MAFTIR,
+    haftarah: "Isaiah 10:32-12:6",
+    haftarah_sephardic: "Isaiah 10:32-12:6",
+};
+
+const SHAVUOT_I: HolidayReading = HolidayReading {
+    holiday: "Shavuot I",
+    aliyot: &[
+        Passage::new("Exodus", "19:1-19:6"),
+        Passage::new("Exodus", "19:7-19:13"),
+        Passage::new("Exodus", "19:14-19:19"),
+        Passage::new("Exodus", "19:20-20:14"),
+        Passage::new("Exodus", "20:15-20:23"),
+    ],
+    maftir: SHAVUOT_MAFTIR,
+    haftarah: "Ezekiel 1:1-28; 3:12",
+    haftarah_sephardic: "Ezekiel 1:1-28; 3:12",
+};
+
+const SHAVUOT_II: HolidayReading = HolidayReading {
+    holiday: "Shavuot II",
+    aliyot: &KOL_HABECHOR_ALIYOT,
+    maftir: SHAVUOT_MAFTIR,
+    haftarah: "Habakkuk 2:20-3:19",
+    haftarah_sephardic: "Habakkuk 2:20-3:19",
+};
+
+/// Returns the offerings read for the given day of Sukkot, counted from 0, preceded in the
+/// Diaspora by those of the previous day, since the day of the festival is in doubt there.
+fn sukkot_offerings(day: usize, israel: bool) -> Passage {
+    let first = if israel { day } else { day - 1 };
+    Passage {
+        first: SUKKOT_OFFERINGS[first].first,
+        ..SUKKOT_OFFERINGS[day]
+    }
+}
+
+/// Returns the reading of Shabbat Chol HaMoed on `date`.
+///
+/// On Sukkot, the maftir reads the offerings of the day.
+fn shabbat_chol_hamoed(date: Hdate, israel: bool) -> HolidayReading {
+    let (maftir, haftarah) = if date.month == HebrewMonth::Tishrei {
+        (
+            sukkot_offerings((date.day - 15) as usize, israel),
+            "Ezekiel 38:18-39:16",
+        )
+    } else {
+        (LAST_DAYS_OF_PESACH_MAFTIR, "Ezekiel 37:1-14")
+    };
+    HolidayReading {
+        holiday: "Shabbat Chol HaMoed",
+        aliyot: &SHABBAT_CHOL_HAMOED_ALIYOT,
+        maftir,
+        haftarah,
+        haftarah_sephardic: haftarah,
+    }
+}
+
+/// Returns the reading of the Yom Tov or Shabbat Chol HaMoed on `date`, if any.
+fn holiday_reading(date: Hdate, israel: bool) -> Option<HolidayReading> {
+    let reading = match (date.month, date.day, israel) {
+        (HebrewMonth::Tishrei, 1, _) => &ROSH_HASHANA_I,
+        (HebrewMonth::Tishrei, 2, _) => &ROSH_HASHANA_II,
+        (HebrewMonth::Tishrei, 10, _) => &YOM_KIPPUR,
+        (HebrewMonth::Tishrei, 15, _) => &SUKKOT_I,
+        (HebrewMonth::Tishrei, 16, false) => &SUKKOT_II,
+        (HebrewMonth::Tishrei, 22, true) => &SIMCHAT_TORAH,
+        (HebrewMonth::Tishrei, 22, false) => &SHMINI_ATZERET,
+        (HebrewMonth::Tishrei, 23, false) => &SIMCHAT_TORAH,
+        (HebrewMonth::Nisan, 15, _) => &PESACH_I,
+        (HebrewMonth::Nisan, 16, false) => &PESACH_II,
+        (HebrewMonth::Nisan, 21, _) => &PESACH_VII,
+        (HebrewMonth::Nisan, 22, false) => &PESACH_VIII,
+        (HebrewMonth::Sivan, 6, _) => &SHAVUOT_I,
+        (HebrewMonth::Sivan, 7, false) => &SHAVUOT_II,
+        (HebrewMonth::Tishrei | HebrewMonth::Nisan, 16..=20, _)
+            if date.get_week_day() == SATURDAY =>
+        {
+            return Some(shabbat_chol_hamoed(date, israel));
+        }
+        _ => return None,
+    };
+    Some(*reading)
+}
+
+impl Reading {
+    /// Returns the Torah and Haftarah readings of `date`, in Israel or in the Diaspora,
+    /// or `None` on days without a Torah reading.
+    ///
+    /// On Shabbat, the maftir and the Haftarah of the parsha are replaced on the special
+    /// Shabbatot, Chanukah and Rosh Chodesh. The Haftarah of Rosh Chodesh and Machar
+    /// Chodesh gives way to the three Haftarot of rebuke and the seven of consolation read
+    /// from the seventeenth of Tammuz until Rosh Hashana. On Yom Tov and Shabbat Chol
+    /// HaMoed, the parsha gives way to the reading of the festival.
+    ///
+    /// On weekdays, the Torah is read on Rosh Chodesh, Chanukah, Purim and the fasts, where
+    /// the Haftarah is the one read at Mincha, and otherwise on Mondays and Thursdays, from
+    /// the beginning of the parsha of the coming Shabbat. On Chol HaMoed, the Torah is read
+    /// every day, from the offerings of the festival.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::{Hdate, HebrewMonth, Reading};
+    ///
+    /// let reading = Reading::new(Hdate::from_ymd(5785, HebrewMonth::Tishrei, 24), false).unwrap();
+    /// assert_eq!(reading.aliyot.len(), 7);
+    /// assert_eq!(reading.aliyot[0].to_string(), "Genesis 1:1-2:3");
+    /// assert_eq!(reading.maftir.unwrap().to_string(), "Genesis 6:5-6:8");
+    /// assert_eq!(reading.haftarah, Some("Isaiah 42:5-43:10"));
+    /// assert_eq!(reading.haftarah_sephardic, Some("Isaiah 42:5-21"));
+    ///
+    /// // Rosh Chodesh Adar 5785 was Shabbat Shekalim.
+    /// let reading = Reading::new(Hdate::from_ymd(5785, HebrewMonth::AdarI, 1), false).unwrap();
+    /// assert_eq!(reading.occasion, Some("Shabbat Shekalim"));
+    /// assert_eq!(reading.maftir.unwrap().to_string(), "Exodus 30:11-30:16");
+    /// assert_eq!(reading.haftarah, Some("II Kings 12:1-17"));
+    ///
+    /// // The Torah isn't read on a regular Sunday.
+    /// assert_eq!(Reading::new(Hdate::from_ymd(5785, HebrewMonth::Tishrei, 25), false), None);
+    /// ```
+    pub fn new(date: Hdate, israel: bool) -> Option<Self> {
+        if let Some(holiday) = holiday_reading(date, israel) {
+            return Some(Self {
+                aliyot: holiday.aliyot.to_vec(),
+                maftir: Some(holiday.maftir),
+                haftarah: Some(holiday.haftarah),
+                haftarah_sephardic: Some(holiday.haftarah_sephardic),
+                occasion: Some(holiday.holiday),
+            });
+        }
+        Self::shabbat(date, israel, |parsha| {
+            Self::parsha(parsha.parsha, parsha.combined)
+        })
+        .or_else(|| Self::weekday(date, israel))
+    }
+
+    /// Returns the readings of Shabbat on `date`, starting from the reading of its parsha
+    /// returned by `parsha_reading`, or `None` if no parsha is read on `date`.
+    pub(crate) fn shabbat(
+        date: Hdate,
+        israel: bool,
+        parsha_reading: impl Fn(&ParshaEvent) -> Self,
+    ) -> Option<Self> {
+        if date.get_week_day() != SATURDAY {
+            return None;
+        }
+        let parsha = parshiyot(date.year, israel)
+            .into_iter()
+            .find(|parsha| parsha.event.date == date)?;
+        let mut reading = parsha_reading(&parsha);
+
+        let tammuz_17 = Hdate::from_ymd(date.year, HebrewMonth::Tamuz, 17);
+        let rebuke_or_consolation = date > tammuz_17;
+        if parsha.parsha == PINCHAS && rebuke_or_consolation {
+            // The first Haftarah of rebuke is Matot's.
+            let matot = &PARSHA_READINGS[MATOT];
+            reading.haftarah = Some(matot.haftarah);
+            reading.haftarah_sephardic = Some(matot.haftarah_sephardic);
+        }
+
+        let tomorrow = date + 1;
+        if date.is_rosh_chodesh() {
+            reading.substitute(&ROSH_CHODESH_READING, !rebuke_or_consolation);
+        } else if tomorrow.is_rosh_chodesh() && !rebuke_or_consolation {
+            reading.substitute(&MACHAR_CHODESH_READING, true);
+        }
+
+        if let Some(chanukah_day) = date.chanukah_day() {
+            let chanukah = Substitution {
+                occasion: "Shabbat Chanukah",
+                maftir: Some(Passage::new("Numbers", CHANUKAH_MAFTIR[chanukah_day])),
+                // The Haftarah of the second Shabbat of Chanukah tells of the lamps of
+                // the Temple.
+                haftarah: if chanukah_day == 7 {
+                    "I Kings 7:40-50"
+                } else {
+                    "Zechariah 2:14-4:7"
+                },
+                haftarah_sephardic: if chanukah_day == 7 {
+                    "I Kings 7:40-50"
+                } else {
+                    "Zechariah 2:14-4:7"
+                },
+            };
+            reading.substitute(&chanukah, true);
+        }
+
+        let special_shabbat = special_shabbatot(date.year)
+            .into_iter()
+            .find(|event| event.as_ref().date == date)
+            .and_then(|event| {
+                SPECIAL_SHABBAT_READINGS
+                    .iter()
+                    .find(|special| special.occasion == event.as_ref().description)
+            });
+        if let Some(special) = special_shabbat {
+            reading.substitute(special, true);
+        }
+        Some(reading)
+    }
+
+    /// Returns the regular reading of a parsha, or of two combined parshiyot.
+    pub(crate) fn parsha(parsha: usize, combined: bool) -> Self {
+        let parsha_reading = if combined {
+            COMBINED_READINGS
+                .iter()
+                .find(|(first, _)| *first == parsha)
+                .map(|(_, reading)| reading)
+                .expect("Only the parshiyot of the table are combined")
+        } else {
+            &PARSHA_READINGS[parsha]
+        };
+        let book = PARSHIYOT_VERSES[parsha].0;
+        Self {
+            aliyot: parsha_reading
+                .aliyot
+                .iter()
+                .map(|verses| Passage::new(book, verses))
+                .collect(),
+            maftir: Some(Passage::new(book, parsha_reading.maftir)),
+            haftarah: Some(parsha_reading.haftarah),
+            haftarah_sephardic: Some(parsha_reading.haftarah_sephardic),
+            occasion: None,
+        }
+    }
+
+    /// Replaces the maftir, when the substitution has one, and the Haftarah if `haftarah`.
+    fn substitute(&mut self, substitution: &Substitution, haftarah: bool) {
+        if substitution.maftir.is_some() {
+            self.maftir = substitution.maftir;
+        }
+        if haftarah {
+            self.haftarah = Some(substitution.haftarah);
+            self.haftarah_sephardic = Some(substitution.haftarah_sephardic);
+        }
+        self.occasion = Some(substitution.occasion);
+    }
+
+    /// Returns the reading of a weekday, or `None` on days without a Torah reading.
+    fn weekday(date: Hdate, israel: bool) -> Option<Self> {
+        let rosh_chodesh = date.is_rosh_chodesh();
+        if let Some(chanukah_day) = date.chanukah_day() {
+            let aliyot = if rosh_chodesh {
+                // Rosh Chodesh is read for the first three aliyot, and Chanukah for the fourth.
+                let mut aliyot = ROSH_CHODESH_ON_CHANUKAH_ALIYOT.to_vec();
+                aliyot.push(Passage::new("Numbers", CHANUKAH_MAFTIR[chanukah_day]));
+                aliyot
+            } else {
+                CHANUKAH_ALIYOT[chanukah_day]
+                    .iter()
+                    .map(|verses| Passage::new("Numbers", verses))
+                    .collect()
+            };
+            return Some(Self::weekday_reading(aliyot, "Chanukah"));
+        }
+        if rosh_chodesh {
+            return Some(Self::weekday_reading(
+                ROSH_CHODESH_ALIYOT.to_vec(),
+                "Rosh Chodesh",
+            ));
+        }
+
+        let fast = holidays_for_year(date.year)
+            .into_iter()
+            .filter(|event| event.as_ref().date == date)
+            .find_map(|event| {
+                FASTS
+                    .into_iter()
+                    .find(|fast| *fast == event.as_ref().description)
+            });
+        if let Some(fast) = fast {
+            return Some(if fast == TISHA_BAV {
+                Self {
+                    haftarah: Some(TISHA_BAV_HAFTARAH),
+                    haftarah_sephardic: Some(TISHA_BAV_HAFTARAH),
+                    ..Self::weekday_reading(TISHA_BAV_ALIYOT.to_vec(), fast)
+                }
+            } else {
+                Self {
+                    haftarah: Some(FAST_DAY_HAFTARAH),
+                    ..Self::weekday_reading(VAYECHAL_ALIYOT.to_vec(), fast)
+                }
+            });
+        }
+
+        if date.is_purim() {
+            return Some(Self::weekday_reading(PURIM_ALIYOT.to_vec(), "Purim"));
+        }
+
+        let first_day_of_chol_hamoed = if israel { 16 } else { 17 };
+        match date.month {
+            HebrewMonth::Tishrei if (first_day_of_chol_hamoed..=21).contains(&date.day) => {
+                return Some(Self::sukkot_chol_hamoed(date, israel));
+            }
+            HebrewMonth::Nisan if (first_day_of_chol_hamoed..=20).contains(&date.day) => {
+                return Some(Self::pesach_chol_hamoed(date, israel));
+            }
+            _ => {}
+        }
+
+        if !matches!(date.get_week_day(), MONDAY | THURSDAY) {
+            return None;
+        }
+        // The beginning of the parsha read on the coming Shabbat.
+        let parsha = parshiyot(date.year, israel)
+            .into_iter()
+            .chain(parshiyot(date.year + 1, israel))
+            .find(|parsha| parsha.event.date > date)?;
+        let parsha = if parsha.parsha == 0 && date.month == HebrewMonth::Tishrei && date.day < 22 {
+            VEZOT_HABERAKHAH
+        } else {
+            parsha.parsha
+        };
+        let book = PARSHIYOT_VERSES[parsha].0;
+        Some(Self {
+            aliyot: WEEKDAY_ALIYOT[parsha]
+                .iter()
+                .map(|verses| Passage::new(book, verses))
+                .collect(),
+            maftir: None,
+            haftarah: None,
+            haftarah_sephardic: None,
+            occasion: None,
+        })
+    }
+
+    /// Returns the reading of a weekday of Chol HaMoed Sukkot, or of Hoshana Raba.
+    ///
+    /// In Israel, the offerings of the day are read four times. In the Diaspora, the first
+    /// three aliyot read the offerings of three consecutive days, starting with the day in
+    /// doubt, and the fourth those of both days in doubt.
+    fn sukkot_chol_hamoed(date: Hdate, israel: bool) -> Self {
+        let day = (date.day - 15) as usize;
+        let aliyot = if israel {
+            vec![SUKKOT_OFFERINGS[day]; 4]
+        } else {
+            let first = (day - 1).min(SUKKOT_OFFERINGS.len() - 3);
+            let mut aliyot = SUKKOT_OFFERINGS[first..first + 3].to_vec();
+            aliyot.push(sukkot_offerings(day, israel));
+            aliyot
+        };
+        let occasion = if date.day == 21 {
+            "Hoshana Raba"
+        } else {
+            "Chol HaMoed Sukkot"
+        };
+        Self::weekday_reading(aliyot, occasion)
+    }
+
+    /// Returns the reading of a weekday of Chol HaMoed Pesach, with the offerings of
+    /// Pesach read by the fourth aliyah.
+    fn pesach_chol_hamoed(date: Hdate, israel: bool) -> Self {
+        let first_day = if israel { 16 } else { 17 };
+        let days = (first_day..=20).map(|day| Hdate::from_ymd(date.year, HebrewMonth::Nisan, day));
+        let shabbat = days.clone().any(|day| day.get_week_day() == SATURDAY);
+        let weekday = days
+            .filter(|day| *day <= date && day.get_week_day() != SATURDAY)
+            .count();
+        let aliyot = PESACH_CHOL_HAMOED_ALIYOT[if israel { 0 } else { 1 }..]
+            .iter()
+            .filter(|aliyot| !(shabbat && **aliyot == PESAL_LECHA_ALIYOT))
+            .nth(weekday - 1)
+            .expect("Chol HaMoed has a reading for each weekday");
+        let mut aliyot = aliyot.to_vec();
+        aliyot.push(LAST_DAYS_OF_PESACH_MAFTIR);
+        Self::weekday_reading(aliyot, "Chol HaMoed Pesach")
+    }
+
+    fn weekday_reading(aliyot: Vec<Passage>, occasion: &'static str) -> Self {
+        Self {
+            aliyot,
+            maftir: None,
+            haftarah: None,
+            haftarah_sephardic: None,
+            occasion: Some(occasion),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reading(month: HebrewMonth, day: u8) -> Reading {
+        Reading::new(Hdate::from_ymd(5785, month, day), false).unwrap()
+    }
+
+    #[test]
+    fn test_rosh_chodesh() {
+        // Rosh Chodesh Cheshvan 5785 was on Shabbat.
+        let noach = reading(HebrewMonth::Cheshvan, 1);
+        assert_eq!(noach.aliyot[0].to_string(), "Genesis 6:9-6:22");
+        assert_eq!(noach.maftir.unwrap().to_string(), "Numbers 28:9-28:15");
+        assert_eq!(noach.haftarah, Some("Isaiah 66:1-24"));
+        assert_eq!(noach.occasion, Some("Shabbat Rosh Chodesh"));
+
+        // Rosh Chodesh Av keeps the second Haftarah of rebuke.
+        let matot_masei = reading(HebrewMonth::Av, 1);
+        assert_eq!(matot_masei.aliyot[6].to_string(), "Numbers 35:9-36:13");
+        assert_eq!(
+            matot_masei.maftir.unwrap().to_string(),
+            "Numbers 28:9-28:15"
+        );
+        assert_eq!(matot_masei.haftarah, Some("Jeremiah 2:4-28; 3:4"));
+        assert_eq!(
+            matot_masei.haftarah_sephardic,
+            Some("Jeremiah 2:4-28; 4:1-2")
+        );
+    }
+
+    #[test]
+    fn test_chanukah() {
+        // The third day of Chanukah 5785 was on Shabbat.
+        let miketz = reading(HebrewMonth::Kislev, 27);
+        assert_eq!(miketz.aliyot[0].to_string(), "Genesis 41:1-41:14");
+        assert_eq!(miketz.maftir.unwrap().to_string(), "Numbers 7:24-7:29");
+        assert_eq!(miketz.haftarah, Some("Zechariah 2:14-4:7"));
+        assert_eq!(miketz.occasion, Some("Shabbat Chanukah"));
+    }
+
+    #[test]
+    fn test_holidays() {
+        // Yom Kippur 5785 was on Shabbat.
+        let yom_kippur = reading(HebrewMonth::Tishrei, 10);
+        assert_eq!(yom_kippur.aliyot.len(), 6);
+        assert_eq!(yom_kippur.maftir.unwrap().to_string(), "Numbers 29:7-29:11");
+        assert_eq!(yom_kippur.occasion, Some("Yom Kippur"));
+
+        let shmini_atzeret = |israel| {
+            Reading::new(Hdate::from_ymd(5785, HebrewMonth::Tishrei, 22), israel)
+                .unwrap()
+                .occasion
+        };
+        assert_eq!(shmini_atzeret(false), Some("Shmini Atzeret"));
+        assert_eq!(shmini_atzeret(true), Some("Simchat Torah"));
+        assert_eq!(
+            Reading::new(Hdate::from_ymd(5785, HebrewMonth::Sivan, 7), true),
+            None
+        );
+        assert_eq!(
+            reading(HebrewMonth::Sivan, 7).haftarah,
+            Some("Habakkuk 2:20-3:19")
+        );
+    }
+
+    #[test]
+    fn test_shabbat_chol_hamoed() {
+        // Tishrei 17, 5785 was a Shabbat.
+        let sukkot =
+            |israel| Reading::new(Hdate::from_ymd(5785, HebrewMonth::Tishrei, 17), israel).unwrap();
+        let diaspora = sukkot(false);
+        assert_eq!(diaspora.occasion, Some("Shabbat Chol HaMoed"));
+        assert_eq!(diaspora.aliyot.len(), 7);
+        assert_eq!(diaspora.aliyot[0].to_string(), "Exodus 33:12-33:16");
+        assert_eq!(diaspora.aliyot[6].to_string(), "Exodus 34:18-34:26");
+        assert_eq!(diaspora.maftir.unwrap().to_string(), "Numbers 29:17-29:22");
+        assert_eq!(diaspora.haftarah, Some("Ezekiel 38:18-39:16"));
+        assert_eq!(
+            sukkot(true).maftir.unwrap().to_string(),
+            "Numbers 29:20-29:22"
+        );
+
+        // Nisan 19, 5784 was a Shabbat.
+        let pesach = Reading::new(Hdate::from_ymd(5784, HebrewMonth::Nisan, 19), false).unwrap();
+        assert_eq!(pesach.occasion, Some("Shabbat Chol HaMoed"));
+        assert_eq!(pesach.maftir.unwrap().to_string(), "Numbers 28:19-28:25");
+        assert_eq!(pesach.haftarah, Some("Ezekiel 37:1-14"));
+    }
+
+    #[test]
+    fn test_weekdays() {
+        // Tzom Gedaliah 5785 was postponed to Sunday.
+        let tzom_gedaliah = reading(HebrewMonth::Tishrei, 4);
+        assert_eq!(tzom_gedaliah.aliyot[0].to_string(), "Exodus 32:11-32:14");
+        assert_eq!(tzom_gedaliah.maftir, None);
+        assert_eq!(tzom_gedaliah.haftarah, Some("Isaiah 55:6-56:8"));
+        assert_eq!(tzom_gedaliah.haftarah_sephardic, None);
+        assert_eq!(
+            reading(HebrewMonth::Av, 9).aliyot[0].to_string(),
+            "Deuteronomy 4:25-4:29"
+        );
+
+        let monday = reading(HebrewMonth::Tishrei, 26);
+        let aliyot: Vec<String> = monday.aliyot.iter().map(Passage::to_string).collect();
+        assert_eq!(
+            aliyot,
+            ["Genesis 6:9-6:16", "Genesis 6:17-6:19", "Genesis 6:20-6:22"]
+        );
+        assert_eq!(monday.occasion, None);
+        assert_eq!(
+            Reading::new(Hdate::from_ymd(5785, HebrewMonth::Tishrei, 27), false),
+            None
+        );
+        // Vezot Haberakhah is read between Yom Kippur and Sukkot, and Bereshit after.
+        assert_eq!(
+            reading(HebrewMonth::Tishrei, 12).aliyot[0].to_string(),
+            "Deuteronomy 33:1-33:7"
+        );
+        let bereshit =
+            Reading::new(Hdate::from_ymd(5781, HebrewMonth::Tishrei, 24), false).unwrap();
+        let aliyot: Vec<String> = bereshit.aliyot.iter().map(Passage::to_string).collect();
+        assert_eq!(
+            aliyot,
+            ["Genesis 1:1-1:5", "Genesis 1:6-1:8", "Genesis 1:9-1:13"]
+        );
+
+        assert_eq!(
+            reading(HebrewMonth::Kislev, 26).aliyot[0].to_string(),
+            "Numbers 7:18-7:20"
+        );
+        // Rosh Chodesh Tevet 5785 was on the sixth day of Chanukah.
+        let rosh_chodesh = reading(HebrewMonth::Kislev, 30);
+        assert_eq!(rosh_chodesh.aliyot.len(), 4);
+        assert_eq!(rosh_chodesh.aliyot[3].to_string(), "Numbers 7:42-7:47");
+        assert_eq!(reading(HebrewMonth::Tevet, 2).occasion, Some("Chanukah"));
+        assert_eq!(
+            Reading::new(Hdate::from_ymd(5785, HebrewMonth::Tevet, 3), false),
+            None
+        );
+        assert_eq!(reading(HebrewMonth::AdarI, 14).occasion, Some("Purim"));
+        assert_eq!(reading(HebrewMonth::Shvat, 1).aliyot.len(), 4);
+    }
+
+    #[test]
+    fn test_chol_hamoed() {
+        let aliyot = |date: Hdate, israel: bool| -> Vec<String> {
+            let reading = Reading::new(date, israel).unwrap();
+            reading.aliyot.iter().map(Passage::to_string).collect()
+        };
+
+        let sukkot = Hdate::from_ymd(5785, HebrewMonth::Tishrei, 19);
+        assert_eq!(
+            aliyot(sukkot, false),
+            [
+                "Numbers 29:23-29:25",
+                "Numbers 29:26-29:28",
+                "Numbers 29:29-29:31",
+                "Numbers 29:23-29:28"
+            ]
+        );
+        assert_eq!(aliyot(sukkot, true), ["Numbers 29:26-29:28"; 4]);
+        let hoshana_raba = Hdate::from_ymd(5785, HebrewMonth::Tishrei, 21);
+        assert_eq!(
+            Reading::new(hoshana_raba, false).unwrap().occasion,
+            Some("Hoshana Raba")
+        );
+        assert_eq!(
+            aliyot(hoshana_raba, false),
+            [
+                "Numbers 29:26-29:28",
+                "Numbers 29:29-29:31",
+                "Numbers 29:32-29:34",
+                "Numbers 29:29-29:34"
+            ]
+        );
+
+        // Nisan 19, 5784 was a Shabbat, on which Pesal Lecha was read.
+        let pesach = |day: u8, israel: bool| -> Vec<String> {
+            aliyot(Hdate::from_ymd(5784, HebrewMonth::Nisan, day), israel)
+        };
+        assert_eq!(
+            pesach(16, true),
+            [
+                "Leviticus 22:26-23:3",
+                "Leviticus 23:4-23:14",
+                "Leviticus 23:15-23:44",
+                "Numbers 28:19-28:25"
+            ]
+        );
+        assert_eq!(pesach(17, false)[0], "Exodus 13:1-13:4");
+        assert_eq!(pesach(17, true)[0], "Exodus 13:1-13:4");
+        assert_eq!(pesach(18, false)[0], "Exodus 22:24-22:26");
+        assert_eq!(pesach(20, false)[0], "Numbers 9:1-9:5");
+        assert_eq!(pesach(20, false)[3], "Numbers 28:19-28:25");
+        // Nisan 22, 5783 was a Thursday, and Isru Chag in Israel.
+        let isru_chag = Reading::new(Hdate::from_ymd(5783, HebrewMonth::Nisan, 22), true).unwrap();
+        assert_eq!(isru_chag.occasion, None);
+        assert_eq!(isru_chag.aliyot[0].to_string(), "Leviticus 9:1-9:6");
+    }
+
+    #[test]
+    fn test_chapter_verses() {
+        for (book, first, last) in PARSHIYOT_VERSES {
+            let passage = Passage::new(book, &format!("{first}-{last}"));
+            for verse_in_book in [passage.first, passage.last] {
+                assert!(chapters(book)[verse_in_book.chapter as usize - 1] >= verse_in_book.verse);
+            }
+        }
+        // The last parsha of each book ends with its last verse.
+        for parsha in [11, 22, 32, 42, 53] {
+            let (book, first, last) = PARSHIYOT_VERSES[parsha];
+            let last = Passage::new(book, &format!("{first}-{last}")).last;
+            assert_eq!(chapters(book).len(), last.chapter as usize);
+            assert_eq!(chapters(book)[last.chapter as usize - 1], last.verse);
+        }
+    }
+
+    fn assert_valid(passage: &Passage) {
+        assert!(passage.first <= passage.last, "{passage}");
+        for verse in [passage.first, passage.last] {
+            assert!(verse.chapter >= 1 && verse.verse >= 1, "{passage}");
+            let chapters = chapters(passage.book);
+            assert!(verse.chapter as usize <= chapters.len(), "{passage}");
+            assert!(
+                chapters[verse.chapter as usize - 1] >= verse.verse,
+                "{passage}"
+            );
+        }
+    }
+
+    fn next_verse(book: &str, verse: Verse) -> Verse {
+        if verse.verse == chapters(book)[verse.chapter as usize - 1] {
+            Verse {
+                chapter: verse.chapter + 1,
+                verse: 1,
+            }
+        } else {
+            Verse {
+                verse: verse.verse + 1,
+                ..verse
+            }
+        }
+    }
+
+    /// Checks that each aliyah starts where the previous one ends, within the same book.
+    fn assert_contiguous(aliyot: &[Passage]) {
+        for passage in aliyot {
+            assert_valid(passage);
+        }
+        for pair in aliyot.windows(2) {
+            if pair[0].book == pair[1].book {
+                assert_eq!(
+                    next_verse(pair[0].book, pair[0].last),
+                    pair[1].first,
+                    "{}",
+                    pair[1]
+                );
+            }
+        }
+    }
+
+    fn passages(book: &'static str, verses: &[&str]) -> Vec<Passage> {
+        verses
+            .iter()
+            .map(|verses| Passage::new(book, verses))
+            .collect()
+    }
+
+    #[test]
+    fn test_reading_tables() {
+        let bounds = |parsha: usize| {
+            let (book, first, last) = PARSHIYOT_VERSES[parsha];
+            Passage::new(book, &format!("{first}-{last}"))
+        };
+        let mut readings: Vec<_> = PARSHA_READINGS
+            .iter()
+            .enumerate()
+            .map(|(parsha, reading)| (parsha, false, reading))
+            .collect();
+        readings.extend(
+            COMBINED_READINGS
+                .iter()
+                .map(|(first, reading)| (*first, true, reading)),
+        );
+        for (parsha, combined, reading) in readings {
+            let first = bounds(parsha);
+            let last = bounds(if combined { parsha + 1 } else { parsha });
+            let aliyot = passages(first.book, &reading.aliyot);
+            assert_contiguous(&aliyot);
+            assert_eq!(aliyot[0].first, first.first, "{}", aliyot[0]);
+            assert_eq!(aliyot[6].last, last.last, "{}", aliyot[6]);
+            let maftir = Passage::new(first.book, reading.maftir);
+            assert_valid(&maftir);
+            assert!(maftir.first >= aliyot[6].first, "{maftir}");
+            assert_eq!(maftir.last, last.last, "{maftir}");
+        }
+        for (parsha, verses) in WEEKDAY_ALIYOT.iter().enumerate() {
+            let bounds = bounds(parsha);
+            let aliyot = passages(bounds.book, verses);
+            assert_contiguous(&aliyot);
+            assert_eq!(aliyot[0].first, bounds.first, "{}", aliyot[0]);
+            assert!(aliyot[2].last <= bounds.last, "{}", aliyot[2]);
+        }
+        for (aliyot, maftir) in CHANUKAH_ALIYOT.iter().zip(CHANUKAH_MAFTIR) {
+            assert_contiguous(&passages("Numbers", aliyot));
+            assert_valid(&Passage::new("Numbers", maftir));
+        }
+        // The second aliyah of Rosh Chodesh repeats the last verse of the first.
+        for passage in ROSH_CHODESH_ALIYOT {
+            assert_valid(&passage);
+        }
+        // Vayechal skips from the plea of Moses to the second tablets.
+        for passage in VAYECHAL_ALIYOT {
+            assert_valid(&passage);
+        }
+        for aliyot in [
+            &ROSH_CHODESH_ON_CHANUKAH_ALIYOT[..],
+            &TISHA_BAV_ALIYOT,
+            &PURIM_ALIYOT,
+            &PESAL_LECHA_ALIYOT,
+            &SUKKOT_ALIYOT,
+            &KOL_HABECHOR_ALIYOT,
+            &SUKKOT_OFFERINGS,
+            &SHABBAT_CHOL_HAMOED_ALIYOT,
+        ] {
+            assert_contiguous(aliyot);
+        }
+        for aliyot in &PESACH_CHOL_HAMOED_ALIYOT {
+            assert_contiguous(aliyot);
+        }
+        for substitution in SPECIAL_SHABBAT_READINGS
+            .iter()
+            .chain([&ROSH_CHODESH_READING, &MACHAR_CHODESH_READING])
+        {
+            if let Some(maftir) = substitution.maftir {
+                assert_valid(&maftir);
+            }
+        }
+        for reading in [
+            ROSH_HASHANA_I,
+            ROSH_HASHANA_II,
+            YOM_KIPPUR,
+            SUKKOT_I,
+            SUKKOT_II,
+            SHMINI_ATZERET,
+            SIMCHAT_TORAH,
+            PESACH_I,
+            PESACH_II,
+            PESACH_VII,
+            PESACH_VIII,
+            SHAVUOT_I,
+            SHAVUOT_II,
+        ] {
+            assert_contiguous(reading.aliyot);
+            assert_valid(&reading.maftir);
+        }
+    }
+
+    #[test]
+    fn test_every_shabbat_has_a_reading() {
+        for year in 5780..5800 {
+            for israel in [false, true] {
+                for parsha in parshiyot(year, israel) {
+                    let reading = Reading::new(parsha.event.date, israel).unwrap();
+                    assert_eq!(reading.aliyot.len(), 7);
+                    assert_eq!(
+                        reading.aliyot[0].book, PARSHIYOT_VERSES[parsha.parsha].0,
+                        "{}",
+                        parsha.event.description
+                    );
+                }
+            }
+        }
+    }
+}
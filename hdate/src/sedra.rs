@@ -0,0 +1,413 @@
+//! The weekly Torah portions (parshiyot) read on Shabbat.
+
+use alloc::vec::Vec;
+
+use crate::parsha_event::ParshaEvent;
+use crate::{Hdate, HebrewMonth};
+
+const SATURDAY: u8 = 6;
+
+/// The names of the 54 parshiyot, in the order they are read.
+pub const PARSHIYOT: [&str; 54] = [
+    "Bereshit",
+    "Noach",
+    "Lech-Lecha",
+    "Vayera",
+    "Chayei Sara",
+    "Toldot",
+    "Vayetzei",
+    "Vayishlach",
+    "Vayeshev",
+    "Miketz",
+    "Vayigash",
+    "Vayechi",
+    "Shemot",
+    "Vaera",
+    "Bo",
+    "Beshalach",
+    "Yitro",
+    "Mishpatim",
+    "Terumah",
+    "Tetzaveh",
+    "Ki Tisa",
+    "Vayakhel",
+    "Pekudei",
+    "Vayikra",
+    "Tzav",
+    "Shmini",
+    "Tazria",
+    "Metzora",
+    "Achrei Mot",
+    "Kedoshim",
+    "Emor",
+    "Behar",
+    "Bechukotai",
+    "Bamidbar",
+    "Nasso",
+    "Beha'alotcha",
+    "Sh'lach",
+    "Korach",
+    "Chukat",
+    "Balak",
+    "Pinchas",
+    "Matot",
+    "Masei",
+    "Devarim",
+    "Vaetchanan",
+    "Eikev",
+    "Re'eh",
+    "Shoftim",
+    "Ki Teitzei",
+    "Ki Tavo",
+    "Nitzavim",
+    "Vayeilech",
+    "Ha'Azinu",
+    "Vezot Haberakhah",
+];
+
+/// The Hebrew names of the 54 parshiyot, in the order they are read.
+pub const PARSHIYOT_HEBREW: [&str; 54] = [
+    "בראשית",
+    "נח",
+    "לך לך",
+    "וירא",
+    "חיי שרה",
+    "תולדות",
+    "ויצא",
+    "וישלח",
+    "וישב",
+    "מקץ",
+    "ויגש",
+    "ויחי",
+    "שמות",
+    "וארא",
+    "בא",
+    "בשלח",
+    "יתרו",
+    "משפטים",
+    "תרומה",
+    "תצוה",
+    "כי תשא",
+    "ויקהל",
+    "פקודי",
+    "ויקרא",
+    "צו",
+    "שמיני",
+    "תזריע",
+    "מצורע",
+    "אחרי מות",
+    "קדושים",
+    "אמור",
+    "בהר",
+    "בחקתי",
+    "במדבר",
+    "נשא",
+    "בהעלותך",
+    "שלח לך",
+    "קרח",
+    "חקת",
+    "בלק",
+    "פינחס",
+    "מטות",
+    "מסעי",
+    "דברים",
+    "ואתחנן",
+    "עקב",
+    "ראה",
+    "שופטים",
+    "כי תצא",
+    "כי תבוא",
+    "נצבים",
+    "וילך",
+    "האזינו",
+    "וזאת הברכה",
+];
+
+/// The book, first verse and last verse of each of the 54 parshiyot.
+pub const PARSHIYOT_VERSES: [(&str, &str, &str); 54] = [
+    ("Genesis", "1:1", "6:8"),
+    ("Genesis", "6:9", "11:32"),
+    ("Genesis", "12:1", "17:27"),
+    ("Genesis", "18:1", "22:24"),
+    ("Genesis", "23:1", "25:18"),
+    ("Genesis", "25:19", "28:9"),
+    ("Genesis", "28:10", "32:3"),
+    ("Genesis", "32:4", "36:43"),
+    ("Genesis", "37:1", "40:23"),
+    ("Genesis", "41:1", "44:17"),
+    ("Genesis", "44:18", "47:27"),
+    ("Genesis", "47:28", "50:26"),
+    ("Exodus", "1:1", "6:1"),
+    ("Exodus", "6:2", "9:35"),
+    ("Exodus", "10:1", "13:16"),
+    ("Exodus", "13:17", "17:16"),
+    ("Exodus", "18:1", "20:23"),
+    ("Exodus", "21:1", "24:18"),
+    ("Exodus", "25:1", "27:19"),
+    ("Exodus", "27:20", "30:10"),
+    ("Exodus", "30:11", "34:35"),
+    ("Exodus", "35:1", "38:20"),
+    ("Exodus", "38:21", "40:38"),
+    ("Leviticus", "1:1", "5:26"),
+    ("Leviticus", "6:1", "8:36"),
+    ("Leviticus", "9:1", "11:47"),
+    ("Leviticus", "12:1", "13:59"),
+    ("Leviticus", "14:1", "15:33"),
+    ("Leviticus", "16:1", "18:30"),
+    ("Leviticus", "19:1", "20:27"),
+    ("Leviticus", "21:1", "24:23"),
+    ("Leviticus", "25:1", "26:2"),
+    ("Leviticus", "26:3", "27:34"),
+    ("Numbers", "1:1", "4:20"),
+    ("Numbers", "4:21", "7:89"),
+    ("Numbers", "8:1", "12:16"),
+    ("Numbers", "13:1", "15:41"),
+    ("Numbers", "16:1", "18:32"),
+    ("Numbers", "19:1", "22:1"),
+    ("Numbers", "22:2", "25:9"),
+    ("Numbers", "25:10", "30:1"),
+    ("Numbers", "30:2", "32:42"),
+    ("Numbers", "33:1", "36:13"),
+    ("Deuteronomy", "1:1", "3:22"),
+    ("Deuteronomy", "3:23", "7:11"),
+    ("Deuteronomy", "7:12", "11:25"),
+    ("Deuteronomy", "11:26", "16:17"),
+    ("Deuteronomy", "16:18", "21:9"),
+    ("Deuteronomy", "21:10", "25:19"),
+    ("Deuteronomy", "26:1", "29:8"),
+    ("Deuteronomy", "29:9", "30:20"),
+    ("Deuteronomy", "31:1", "31:30"),
+    ("Deuteronomy", "32:1", "32:52"),
+    ("Deuteronomy", "33:1", "34:12"),
+];
+
+pub(crate) const VAYAKHEL: usize = 21;
+pub(crate) const TAZRIA: usize = 26;
+pub(crate) const ACHREI_MOT: usize = 28;
+pub(crate) const BEHAR: usize = 31;
+const BAMIDBAR: usize = 33;
+pub(crate) const CHUKAT: usize = 38;
+pub(crate) const PINCHAS: usize = 40;
+pub(crate) const MATOT: usize = 41;
+const DEVARIM: usize = 43;
+pub(crate) const NITZAVIM: usize = 50;
+const VAYEILECH: usize = 51;
+const HAAZINU: usize = 52;
+
+/// The parshiyot combined with the following one before Shavuot, in order of precedence.
+const COMBINED_BEFORE_SHAVUOT: [usize; 4] = [TAZRIA, ACHREI_MOT, BEHAR, VAYAKHEL];
+/// The parshiyot combined with the following one between Shavuot and Tish'a B'Av,
+/// in order of precedence.
+const COMBINED_AFTER_SHAVUOT: [usize; 2] = [MATOT, CHUKAT];
+
+/// Returns whether the Shabbat on `date` is Yom Tov or Chol HaMoed, displacing the weekly parsha.
+fn is_holiday(date: Hdate, israel: bool) -> bool {
+    let last_day = |israel_day, diaspora_day| if israel { israel_day } else { diaspora_day };
+    match date.month {
+        HebrewMonth::Tishrei => {
+            [1, 2, 10].contains(&date.day) || (15..=last_day(22, 23)).contains(&date.day)
+        }
+        HebrewMonth::Nisan => (15..=last_day(21, 22)).contains(&date.day),
+        HebrewMonth::Sivan => (6..=last_day(6, 7)).contains(&date.day),
+        _ => false,
+    }
+}
+
+/// Returns the Shabbatot from `first` until `last`, inclusive, on which a parsha is read.
+fn shabbatot(first: Hdate, last: Hdate, israel: bool) -> impl Iterator<Item = Hdate> {
+    (0..)
+        .map(move |week| first + week * 7)
+        .take_while(move |date| *date <= last)
+        .filter(move |date| !is_holiday(*date, israel))
+}
+
+/// Returns the parshiyot combined with the following one in the Diaspora during the given year.
+///
+/// Devarim is always read on the Shabbat before Tish'a B'Av, so the number of pairs to combine
+/// is set by the number of Shabbatot available until then. They are distributed so that
+/// Bamidbar is read on the Shabbat before Shavuot, unless the year leaves a Shabbat to spare,
+/// in which case Nasso is read before Shavuot too.
+fn diaspora_combined(bereshit: Hdate, bamidbar: Hdate, chazon: Hdate) -> Vec<usize> {
+    let combinations = DEVARIM - shabbatot(bereshit, chazon - 7, false).count();
+    let before_shavuot =
+        (BAMIDBAR + 1).saturating_sub(shabbatot(bereshit, bamidbar, false).count());
+    COMBINED_BEFORE_SHAVUOT[..before_shavuot]
+        .iter()
+        .chain(&COMBINED_AFTER_SHAVUOT[..combinations - before_shavuot])
+        .copied()
+        .collect()
+}
+
+/// Returns the weekly parshiyot read on the Shabbatot of the given Hebrew year, in Israel
+/// or in the Diaspora, in chronological order.
+///
+/// No parsha is read on a Shabbat that falls on Yom Tov or Chol HaMoed. When the last day
+/// of Pesach or the second day of Shavuot falls on Shabbat, the Diaspora reads the holiday
+/// portion while Israel continues with the next parsha, until the Diaspora catches up by
+/// combining a pair of parshiyot that Israel reads separately.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{sedra, Hdate, HebrewMonth};
+///
+/// let parshiyot = sedra::parshiyot(5785, false);
+/// assert_eq!(parshiyot[0].event.description, "Parashat Ha'Azinu");
+/// let tazria = parshiyot
+///     .iter()
+///     .find(|parsha| parsha.names()[0] == "Tazria")
+///     .unwrap();
+/// assert_eq!(tazria.event.description, "Parashat Tazria-Metzora");
+/// assert_eq!(tazria.event.date, Hdate::from_ymd(5785, HebrewMonth::Iyyar, 5));
+/// ```
+pub fn parshiyot(year: u32, israel: bool) -> Vec<ParshaEvent> {
+    let rosh_hashana = Hdate::from_ymd(year, HebrewMonth::Tishrei, 1);
+    let next_rosh_hashana = Hdate::from_ymd(year + 1, HebrewMonth::Tishrei, 1);
+    let shabbat_shuva = (rosh_hashana + 7).on_or_before(SATURDAY);
+    let bereshit = Hdate::from_ymd(year, HebrewMonth::Tishrei, 29).on_or_before(SATURDAY);
+    let bamidbar = Hdate::from_ymd(year, HebrewMonth::Sivan, 5).on_or_before(SATURDAY);
+    let chazon = Hdate::from_ymd(year, HebrewMonth::Av, 9).on_or_before(SATURDAY);
+
+    let mut events = Vec::new();
+
+    // Vayeilech is read on Shabbat Shuva when it wasn't read with Nitzavim the year before.
+    if matches!(rosh_hashana.get_week_day(), 1 | 2) {
+        events.push(ParshaEvent::new(shabbat_shuva, VAYEILECH, false, israel));
+        events.push(ParshaEvent::new(shabbat_shuva + 7, HAAZINU, false, israel));
+    } else {
+        events.push(ParshaEvent::new(shabbat_shuva, HAAZINU, false, israel));
+    }
+
+    let mut combined = diaspora_combined(bereshit, bamidbar, chazon);
+    if israel {
+        // Israel reads a parsha on the Shabbat the Diaspora observes as the last day of
+        // Pesach or as the second day of Shavuot, and catches up on a later combination.
+        let eighth_day_of_pesach = Hdate::from_ymd(year, HebrewMonth::Nisan, 22);
+        let second_day_of_shavuot = Hdate::from_ymd(year, HebrewMonth::Sivan, 7);
+        let separated: &[usize] = if eighth_day_of_pesach.get_week_day() == SATURDAY {
+            &[BEHAR, MATOT]
+        } else if second_day_of_shavuot.get_week_day() == SATURDAY {
+            &[CHUKAT]
+        } else {
+            &[]
+        };
+        if let Some(position) = combined
+            .iter()
+            .position(|parsha| separated.contains(parsha))
+        {
+            combined.remove(position);
+        }
+    }
+
+    let mut parsha = 0;
+    for date in shabbatot(bereshit, chazon - 7, israel) {
+        let is_combined = combined.contains(&parsha);
+        events.push(ParshaEvent::new(date, parsha, is_combined, israel));
+        parsha += if is_combined { 2 } else { 1 };
+    }
+    debug_assert_eq!(parsha, DEVARIM);
+
+    for (week, parsha) in (DEVARIM..NITZAVIM).enumerate() {
+        events.push(ParshaEvent::new(
+            chazon + week as i32 * 7,
+            parsha,
+            false,
+            israel,
+        ));
+    }
+    // Nitzavim and Vayeilech are read together when there is no Shabbat between
+    // Rosh Hashana and Yom Kippur to read Vayeilech on.
+    let nitzavim_vayeilech = matches!(next_rosh_hashana.get_week_day(), 4 | SATURDAY);
+    events.push(ParshaEvent::new(
+        chazon + 7 * 7,
+        NITZAVIM,
+        nitzavim_vayeilech,
+        israel,
+    ));
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(parshiyot: &[ParshaEvent], description: &str) -> Option<Hdate> {
+        parshiyot
+            .iter()
+            .find(|parsha| parsha.event.description == description)
+            .map(|parsha| parsha.event.date)
+    }
+
+    #[test]
+    fn test_parshiyot_5785() {
+        let parshiyot = parshiyot(5785, false);
+        let descriptions: Vec<&str> = parshiyot
+            .iter()
+            .map(|parsha| parsha.event.description.as_str())
+            .collect();
+        assert_eq!(descriptions.len(), 48);
+        assert_eq!(
+            descriptions[..3],
+            ["Parashat Ha'Azinu", "Parashat Bereshit", "Parashat Noach"]
+        );
+        assert_eq!(
+            find(&parshiyot, "Parashat Tazria-Metzora"),
+            Some(Hdate::from_ymd(5785, HebrewMonth::Iyyar, 5))
+        );
+        assert_eq!(
+            find(&parshiyot, "Parashat Matot-Masei"),
+            Some(Hdate::from_ymd(5785, HebrewMonth::Av, 1))
+        );
+        assert_eq!(
+            find(&parshiyot, "Parashat Devarim"),
+            Some(Hdate::from_ymd(5785, HebrewMonth::Av, 8))
+        );
+        // Rosh Hashana 5786 fell on a Tuesday, leaving Vayeilech to Shabbat Shuva.
+        assert_eq!(descriptions.last(), Some(&"Parashat Nitzavim"));
+        assert_eq!(
+            super::parshiyot(5786, false)[0].event.description,
+            "Parashat Vayeilech"
+        );
+    }
+
+    #[test]
+    fn test_parshiyot_israel() {
+        // The eighth day of Pesach 5778 fell on Shabbat, and Israel read Behar and
+        // Bechukotai separately to realign with the Diaspora.
+        let diaspora = parshiyot(5778, false);
+        let israel = parshiyot(5778, true);
+        assert!(find(&diaspora, "Parashat Behar-Bechukotai").is_some());
+        assert_eq!(find(&israel, "Parashat Behar-Bechukotai"), None);
+        assert_eq!(
+            find(&israel, "Parashat Bechukotai"),
+            find(&diaspora, "Parashat Behar-Bechukotai")
+        );
+        assert_eq!(
+            find(&israel, "Parashat Achrei Mot-Kedoshim"),
+            find(&diaspora, "Parashat Achrei Mot-Kedoshim").map(|date| date - 7)
+        );
+    }
+
+    #[test]
+    fn test_parshiyot_cover_the_torah() {
+        for year in 5600..6000 {
+            for israel in [false, true] {
+                let parshiyot = super::parshiyot(year, israel);
+                let bereshit = parshiyot
+                    .iter()
+                    .position(|parsha| parsha.parsha == 0)
+                    .unwrap();
+                let mut expected = 0;
+                for parsha in &parshiyot[bereshit..] {
+                    assert_eq!(parsha.parsha, expected, "{year} {israel}");
+                    assert_eq!(parsha.event.date.get_week_day(), SATURDAY);
+                    expected += if parsha.combined { 2 } else { 1 };
+                }
+                assert!(expected == VAYEILECH || expected == HAAZINU);
+                assert!(parshiyot
+                    .windows(2)
+                    .all(|pair| pair[1].event.date - 7 >= pair[0].event.date));
+            }
+        }
+    }
+}
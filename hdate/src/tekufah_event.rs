@@ -0,0 +1,101 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use chrono::NaiveDateTime;
+use hdate_core::hebrew::HebrewDate;
+use hdate_core::tekufah::{Calculation, Season, Tekufah};
+
+use crate::locale::{month_name, Locale};
+use crate::{CalendarEvent, Event, Flags, Hdate, HebrewMonth};
+
+/// A tekufah, an equinox or solstice of the Hebrew calendar, falling on `event.date`. A
+/// tekufah falling after 18:00 is on the next Hebrew day, which starts in the evening.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TekufahEvent {
+    pub event: Event,
+    pub tekufah: Tekufah,
+    /// The time of the tekufah, in the local mean time of Jerusalem.
+    pub time: NaiveDateTime,
+}
+
+impl TekufahEvent {
+    /// Creates the event of the given tekufah, or `None` if it falls out of the supported
+    /// range of dates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::tekufah::{Calculation, Season, Tekufah};
+    /// use hdate::{Hdate, HebrewMonth, TekufahEvent};
+    ///
+    /// let tekufah = Tekufah::new(5785, Season::Tishrei, Calculation::Shmuel);
+    /// let event = TekufahEvent::new(tekufah).unwrap();
+    /// assert_eq!(event.event.description, "Tekufat Tishrei");
+    /// assert_eq!(event.event.date, Hdate::from_ymd(5785, HebrewMonth::Tishrei, 5));
+    /// assert_eq!(event.time.format("%H:%M").to_string(), "03:00");
+    /// ```
+    pub fn new(tekufah: Tekufah) -> Option<Self> {
+        let time = tekufah.date_time()?;
+        let date = HebrewDate::try_from_absolute(tekufah.hebrew_day()).ok()?;
+        let date = Hdate::from_ymd(date.year, date.month, date.day);
+        Some(Self {
+            event: Event::new(date, format!("Tekufat {}", tekufah.season), Flags::None),
+            tekufah,
+            time,
+        })
+    }
+}
+
+/// Returns the four tekufot of the given Hebrew year, from Tekufat Tishrei to Tekufat Tamuz.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::tekufah::Calculation;
+/// use hdate::tekufah_event::tekufot;
+/// use hdate::{Hdate, HebrewMonth};
+///
+/// let tekufot = tekufot(5785, Calculation::Shmuel);
+/// assert_eq!(tekufot.len(), 4);
+/// assert_eq!(tekufot[2].time.to_string(), "2025-04-07 18:00:00");
+/// // The evening of 7 April 2025 is already the 10th of Nisan.
+/// assert_eq!(tekufot[2].event.date, Hdate::from_ymd(5785, HebrewMonth::Nisan, 10));
+/// ```
+pub fn tekufot(year: u32, calculation: Calculation) -> Vec<TekufahEvent> {
+    [Season::Tishrei, Season::Tevet, Season::Nisan, Season::Tamuz]
+        .into_iter()
+        .filter_map(|season| TekufahEvent::new(Tekufah::new(year, season, calculation)))
+        .collect()
+}
+
+impl AsRef<Event> for TekufahEvent {
+    fn as_ref(&self) -> &Event {
+        &self.event
+    }
+}
+
+impl CalendarEvent for TekufahEvent {
+    fn render(&self, locale: Locale) -> String {
+        let month = match self.tekufah.season {
+            Season::Tishrei => HebrewMonth::Tishrei,
+            Season::Tevet => HebrewMonth::Tevet,
+            Season::Nisan => HebrewMonth::Nisan,
+            Season::Tamuz => HebrewMonth::Tamuz,
+        };
+        match locale {
+            Locale::English => self.event.description.clone(),
+            Locale::Hebrew => format!("תקופת {}", month_name(month, self.tekufah.year, locale)),
+        }
+    }
+
+    fn memo(&self) -> Option<String> {
+        Some(self.time.format("%H:%M").to_string())
+    }
+}
+
+impl From<TekufahEvent> for Event {
+    fn from(value: TekufahEvent) -> Self {
+        value.event
+    }
+}
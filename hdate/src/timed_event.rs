@@ -0,0 +1,111 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use chrono::{DateTime, FixedOffset, Utc};
+
+use crate::locale::{translate, Locale};
+use crate::{CalendarEvent, Event, Flags, Hdate};
+
+/// The beginning or end of a holiday at a given time of `event.date`, such as the beginning
+/// of a fast.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimedEvent {
+    pub event: Event,
+    /// The time of the event, in UTC, or `None` when the sun doesn't reach the position
+    /// setting it on that day.
+    pub time: Option<DateTime<Utc>>,
+    /// The title of the holiday the event belongs to.
+    pub holiday: String,
+    /// Whether the event is the beginning of the holiday, rather than its end.
+    pub begins: bool,
+}
+
+impl TimedEvent {
+    /// Creates the beginning or end of `holiday`, titled "{holiday} begins" or
+    /// "{holiday} ends".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate::locale::Locale;
+    /// use hdate::{CalendarEvent, Flags, Hdate, HebrewMonth, TimedEvent};
+    ///
+    /// let date = Hdate::from_ymd(5785, HebrewMonth::Tishrei, 10);
+    /// let ends = TimedEvent::new(date, "Yom Kippur", false, Flags::MajorFast, None);
+    /// assert_eq!(ends.event.description, "Yom Kippur ends");
+    /// assert_eq!(ends.render(Locale::Hebrew), "סיום יום כפור");
+    /// ```
+    pub fn new(
+        date: Hdate,
+        holiday: &str,
+        begins: bool,
+        mask: Flags,
+        time: Option<DateTime<Utc>>,
+    ) -> Self {
+        let description = if begins {
+            format!("{holiday} begins")
+        } else {
+            format!("{holiday} ends")
+        };
+        Self {
+            event: Event::new(date, description, mask),
+            time,
+            holiday: holiday.to_string(),
+            begins,
+        }
+    }
+
+    /// Returns the title of the event followed by its time at the given offset from UTC,
+    /// or only the title when it has no time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use hdate::locale::Locale;
+    /// use hdate::{Flags, Hdate, HebrewMonth, TimedEvent};
+    ///
+    /// let date = Hdate::from_ymd(5785, HebrewMonth::Tishrei, 9);
+    /// let time = Utc.with_ymd_and_hms(2024, 10, 11, 14, 35, 0).unwrap();
+    /// let flags = Flags::MajorFast | Flags::Erev;
+    /// let begins = TimedEvent::new(date, "Yom Kippur", true, flags, Some(time));
+    /// let jerusalem = FixedOffset::east_opt(3 * 3600).unwrap();
+    /// assert_eq!(begins.render_at(Locale::English, jerusalem), "Yom Kippur begins 17:35");
+    /// assert_eq!(begins.render_at(Locale::Hebrew, jerusalem), "תחילת יום כפור 17:35");
+    /// ```
+    pub fn render_at(&self, locale: Locale, offset: FixedOffset) -> String {
+        let title = self.render(locale);
+        match self.time {
+            Some(time) => format!("{title} {}", time.with_timezone(&offset).format("%H:%M")),
+            None => title,
+        }
+    }
+}
+
+impl AsRef<Event> for TimedEvent {
+    fn as_ref(&self) -> &Event {
+        &self.event
+    }
+}
+
+impl CalendarEvent for TimedEvent {
+    fn render(&self, locale: Locale) -> String {
+        match locale {
+            Locale::English => self.event.description.clone(),
+            Locale::Hebrew if self.begins => {
+                format!("תחילת {}", translate(&self.holiday, locale))
+            }
+            Locale::Hebrew => format!("סיום {}", translate(&self.holiday, locale)),
+        }
+    }
+
+    fn memo(&self) -> Option<String> {
+        Some(self.holiday.clone())
+    }
+}
+
+impl From<TimedEvent> for Event {
+    fn from(value: TimedEvent) -> Self {
+        value.event
+    }
+}
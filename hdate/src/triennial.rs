@@ -0,0 +1,2291 @@
+//! The triennial cycle, in which a third of each parsha is read every year, completing
+//! the Torah in three years.
+//!
+//! The aliyot of each year follow the table of the triennial cycle of the Conservative
+//! movement. Nitzavim, Vayeilech and Ha'Azinu, too short to be divided, are read in full
+//! every year.
+
+use alloc::vec::Vec;
+
+use crate::parsha_event::ParshaEvent;
+use crate::reading::{Passage, Reading};
+use crate::sedra::{parshiyot, PARSHIYOT_VERSES};
+use crate::{Hdate, HebrewMonth};
+
+/// The Hebrew year in which the current series of triennial cycles started.
+pub const CYCLE_START_YEAR: u32 = 5744;
+
+/// The reading of a parsha in one year of the cycle: its seven aliyot and its maftir,
+/// with the verses of the book it belongs to.
+type YearReading = ([&'static str; 7], &'static str);
+
+/// The readings of each parsha read on Shabbat, in each year of the cycle.
+static TRIENNIAL_READINGS: [[YearReading; 3]; 53] = [
+    // Bereshit
+    [
+        (
+            [
+                "1:1-1:5",
+                "1:6-1:8",
+                "1:9-1:13",
+                "1:14-1:19",
+                "1:20-1:23",
+                "1:24-1:31",
+                "2:1-2:3",
+            ],
+            "2:1-2:3",
+        ),
+        (
+            [
+                "2:4-2:9",
+                "2:10-2:19",
+                "2:20-2:25",
+                "3:1-3:21",
+                "3:22-3:24",
+                "4:1-4:18",
+                "4:19-4:26",
+            ],
+            "4:23-4:26",
+        ),
+        (
+            [
+                "5:1-5:5",
+                "5:6-5:8",
+                "5:9-5:14",
+                "5:15-5:20",
+                "5:21-5:24",
+                "5:25-5:31",
+                "5:32-6:8",
+            ],
+            "6:5-6:8",
+        ),
+    ],
+    // Noach
+    [
+        (
+            [
+                "6:9-6:16",
+                "6:17-6:19",
+                "6:20-6:22",
+                "7:1-7:9",
+                "7:10-7:16",
+                "7:17-7:24",
+                "8:1-8:14",
+            ],
+            "8:12-8:14",
+        ),
+        (
+            [
+                "8:15-8:22",
+                "9:1-9:7",
+                "9:8-9:17",
+                "9:18-9:29",
+                "10:1-10:14",
+                "10:15-10:20",
+                "10:21-10:32",
+            ],
+            "10:28-10:32",
+        ),
+        (
+            [
+                "11:1-11:4",
+                "11:5-11:9",
+                "11:10-11:13",
+                "11:14-11:17",
+                "11:18-11:21",
+                "11:22-11:25",
+                "11:26-11:32",
+            ],
+            "11:29-11:32",
+        ),
+    ],
+    // Lech-Lecha
+    [
+        (
+            [
+                "12:1-12:3",
+                "12:4-12:9",
+                "12:10-12:13",
+                "12:14-12:20",
+                "13:1-13:4",
+                "13:5-13:11",
+                "13:12-13:18",
+            ],
+            "13:16-13:18",
+        ),
+        (
+            [
+                "14:1-14:9",
+                "14:10-14:16",
+                "14:17-14:20",
+                "14:21-14:24",
+                "15:1-15:6",
+                "15:7-15:16",
+                "15:17-15:21",
+            ],
+            "15:18-15:21",
+        ),
+        (
+            [
+                "16:1-16:6",
+                "16:7-16:9",
+                "16:10-16:16",
+                "17:1-17:6",
+                "17:7-17:17",
+                "17:18-17:23",
+                "17:24-17:27",
+            ],
+            "17:24-17:27",
+        ),
+    ],
+    // Vayera
+    [
+        (
+            [
+                "18:1-18:5",
+                "18:6-18:8",
+                "18:9-18:14",
+                "18:15-18:21",
+                "18:22-18:26",
+                "18:27-18:30",
+                "18:31-18:33",
+            ],
+            "18:31-18:33",
+        ),
+        (
+            [
+                "19:1-19:11",
+                "19:12-19:20",
+                "19:21-19:29",
+                "19:30-19:38",
+                "20:1-20:8",
+                "20:9-20:14",
+                "20:15-20:18",
+            ],
+            "20:15-20:18",
+        ),
+        (
+            [
+                "21:1-21:4",
+                "21:5-21:13",
+                "21:14-21:21",
+                "21:22-21:34",
+                "22:1-22:8",
+                "22:9-22:19",
+                "22:20-22:24",
+            ],
+            "22:20-22:24",
+        ),
+    ],
+    // Chayei Sara
+    [
+        (
+            [
+                "23:1-23:4",
+                "23:5-23:7",
+                "23:8-23:12",
+                "23:13-23:16",
+                "23:17-23:20",
+                "24:1-24:4",
+                "24:5-24:9",
+            ],
+            "24:5-24:9",
+        ),
+        (
+            [
+                "24:10-24:14",
+                "24:15-24:20",
+                "24:21-24:26",
+                "24:27-24:33",
+                "24:34-24:41",
+                "24:42-24:49",
+                "24:50-24:52",
+            ],
+            "24:50-24:52",
+        ),
+        (
+            [
+                "24:53-24:58",
+                "24:59-24:61",
+                "24:62-24:67",
+                "25:1-25:6",
+                "25:7-25:11",
+                "25:12-25:15",
+                "25:16-25:18",
+            ],
+            "25:16-25:18",
+        ),
+    ],
+    // Toldot
+    [
+        (
+            [
+                "25:19-25:22",
+                "25:23-25:26",
+                "25:27-25:34",
+                "26:1-26:5",
+                "26:6-26:12",
+                "26:13-26:16",
+                "26:17-26:22",
+            ],
+            "26:19-26:22",
+        ),
+        (
+            [
+                "26:23-26:29",
+                "26:30-26:35",
+                "27:1-27:4",
+                "27:5-27:13",
+                "27:14-27:17",
+                "27:18-27:23",
+                "27:24-27:27",
+            ],
+            "27:24-27:27",
+        ),
+        (
+            [
+                "27:28-27:30",
+                "27:31-27:33",
+                "27:34-27:37",
+                "27:38-27:40",
+                "27:41-27:46",
+                "28:1-28:4",
+                "28:5-28:9",
+            ],
+            "28:5-28:9",
+        ),
+    ],
+    // Vayetzei
+    [
+        (
+            [
+                "28:10-28:12",
+                "28:13-28:17",
+                "28:18-28:22",
+                "29:1-29:8",
+                "29:9-29:17",
+                "29:18-29:33",
+                "29:34-30:13",
+            ],
+            "30:9-30:13",
+        ),
+        (
+            [
+                "30:14-30:16",
+                "30:17-30:21",
+                "30:22-30:27",
+                "30:28-30:36",
+                "30:37-30:43",
+                "31:1-31:9",
+                "31:10-31:16",
+            ],
+            "31:14-31:16",
+        ),
+        (
+            [
+                "31:17-31:21",
+                "31:22-31:24",
+                "31:25-31:35",
+                "31:36-31:42",
+                "31:43-31:45",
+                "31:46-31:50",
+                "31:51-32:3",
+            ],
+            "32:1-32:3",
+        ),
+    ],
+    // Vayishlach
+    [
+        (
+            [
+                "32:4-32:6",
+                "32:7-32:9",
+                "32:10-32:13",
+                "32:14-32:22",
+                "32:23-32:30",
+                "32:31-33:5",
+                "33:6-33:20",
+            ],
+            "33:18-33:20",
+        ),
+        (
+            [
+                "34:1-34:4",
+                "34:5-34:12",
+                "34:13-34:17",
+                "34:18-34:23",
+                "34:24-34:31",
+                "35:1-35:7",
+                "35:8-35:15",
+            ],
+            "35:13-35:15",
+        ),
+        (
+            [
+                "35:16-35:26",
+                "35:27-35:29",
+                "36:1-36:8",
+                "36:9-36:19",
+                "36:20-36:30",
+                "36:31-36:39",
+                "36:40-36:43",
+            ],
+            "36:40-36:43",
+        ),
+    ],
+    // Vayeshev
+    [
+        (
+            [
+                "37:1-37:3",
+                "37:4-37:7",
+                "37:8-37:11",
+                "37:12-37:17",
+                "37:18-37:22",
+                "37:23-37:28",
+                "37:29-37:36",
+            ],
+            "37:34-37:36",
+        ),
+        (
+            [
+                "38:1-38:5",
+                "38:6-38:11",
+                "38:12-38:14",
+                "38:15-38:19",
+                "38:20-38:23",
+                "38:24-38:26",
+                "38:27-38:30",
+            ],
+            "38:27-38:30",
+        ),
+        (
+            [
+                "39:1-39:6",
+                "39:7-39:18",
+                "39:19-39:23",
+                "40:1-40:8",
+                "40:9-40:15",
+                "40:16-40:19",
+                "40:20-40:23",
+            ],
+            "40:20-40:23",
+        ),
+    ],
+    // Miketz
+    [
+        (
+            [
+                "41:1-41:4",
+                "41:5-41:7",
+                "41:8-41:14",
+                "41:15-41:24",
+                "41:25-41:38",
+                "41:39-41:43",
+                "41:44-41:52",
+            ],
+            "41:50-41:52",
+        ),
+        (
+            [
+                "41:53-41:57",
+                "42:1-42:5",
+                "42:6-42:18",
+                "42:19-42:28",
+                "42:29-42:38",
+                "43:1-43:7",
+                "43:8-43:15",
+            ],
+            "43:13-43:15",
+        ),
+        (
+            [
+                "43:16-43:18",
+                "43:19-43:25",
+                "43:26-43:29",
+                "43:30-43:34",
+                "44:1-44:6",
+                "44:7-44:10",
+                "44:11-44:17",
+            ],
+            "44:14-44:17",
+        ),
+    ],
+    // Vayigash
+    [
+        (
+            [
+                "44:18-44:20",
+                "44:21-44:24",
+                "44:25-44:30",
+                "44:31-44:34",
+                "45:1-45:3",
+                "45:4-45:5",
+                "45:6-45:7",
+            ],
+            "45:5-45:7",
+        ),
+        (
+            [
+                "45:8-45:10",
+                "45:11-45:13",
+                "45:14-45:18",
+                "45:19-45:21",
+                "45:22-45:27",
+                "45:28-46:4",
+                "46:5-46:27",
+            ],
+            "46:24-46:27",
+        ),
+        (
+            [
+                "46:28-46:30",
+                "46:31-46:34",
+                "47:1-47:6",
+                "47:7-47:10",
+                "47:11-47:19",
+                "47:20-47:22",
+                "47:23-47:27",
+            ],
+            "47:25-47:27",
+        ),
+    ],
+    // Vayechi
+    [
+        (
+            [
+                "47:28-47:31",
+                "48:1-48:3",
+                "48:4-48:9",
+                "48:10-48:13",
+                "48:14-48:16",
+                "48:17-48:19",
+                "48:20-48:22",
+            ],
+            "48:20-48:22",
+        ),
+        (
+            [
+                "49:1-49:4",
+                "49:5-49:7",
+                "49:8-49:12",
+                "49:13-49:15",
+                "49:16-49:18",
+                "49:19-49:21",
+                "49:22-49:26",
+            ],
+            "49:22-49:26",
+        ),
+        (
+            [
+                "49:27-49:30",
+                "49:31-49:33",
+                "50:1-50:6",
+                "50:7-50:9",
+                "50:10-50:14",
+                "50:15-50:20",
+                "50:21-50:26",
+            ],
+            "50:23-50:26",
+        ),
+    ],
+    // Shemot
+    [
+        (
+            [
+                "1:1-1:7",
+                "1:8-1:12",
+                "1:13-1:17",
+                "1:18-1:22",
+                "2:1-2:10",
+                "2:11-2:15",
+                "2:16-2:25",
+            ],
+            "2:23-2:25",
+        ),
+        (
+            [
+                "3:1-3:6",
+                "3:7-3:10",
+                "3:11-3:15",
+                "3:16-3:22",
+                "4:1-4:5",
+                "4:6-4:9",
+                "4:10-4:17",
+            ],
+            "4:14-4:17",
+        ),
+        (
+            [
+                "4:18-4:20",
+                "4:21-4:26",
+                "4:27-4:31",
+                "5:1-5:5",
+                "5:6-5:9",
+                "5:10-5:14",
+                "5:15-6:1",
+            ],
+            "5:22-6:1",
+        ),
+    ],
+    // Vaera
+    [
+        (
+            [
+                "6:2-6:5",
+                "6:6-6:9",
+                "6:10-6:13",
+                "6:14-6:20",
+                "6:21-6:25",
+                "6:26-6:30",
+                "7:1-7:7",
+            ],
+            "7:5-7:7",
+        ),
+        (
+            [
+                "7:8-7:13",
+                "7:14-7:18",
+                "7:19-7:25",
+                "7:26-7:29",
+                "8:1-8:6",
+                "8:7-8:11",
+                "8:12-8:15",
+            ],
+            "8:12-8:15",
+        ),
+        (
+            [
+                "8:16-8:19",
+                "8:20-8:28",
+                "9:1-9:7",
+                "9:8-9:12",
+                "9:13-9:21",
+                "9:22-9:26",
+                "9:27-9:35",
+            ],
+            "9:33-9:35",
+        ),
+    ],
+    // Bo
+    [
+        (
+            [
+                "10:1-10:3",
+                "10:4-10:6",
+                "10:7-10:11",
+                "10:12-10:15",
+                "10:16-10:23",
+                "10:24-10:29",
+                "11:1-11:3",
+            ],
+            "11:1-11:3",
+        ),
+        (
+            [
+                "11:4-11:8",
+                "11:9-11:10",
+                "12:1-12:10",
+                "12:11-12:13",
+                "12:14-12:16",
+                "12:17-12:20",
+                "12:21-12:28",
+            ],
+            "12:25-12:28",
+        ),
+        (
+            [
+                "12:29-12:32",
+                "12:33-12:36",
+                "12:37-12:42",
+                "12:43-12:51",
+                "13:1-13:4",
+                "13:5-13:10",
+                "13:11-13:16",
+            ],
+            "13:14-13:16",
+        ),
+    ],
+    // Beshalach
+    [
+        (
+            [
+                "13:17-13:22",
+                "14:1-14:8",
+                "14:9-14:14",
+                "14:15-14:25",
+                "14:26-15:10",
+                "15:11-15:21",
+                "15:22-15:26",
+            ],
+            "15:22-15:26",
+        ),
+        (
+            [
+                "15:27-16:3",
+                "16:4-16:7",
+                "16:8-16:10",
+                "16:11-16:15",
+                "16:16-16:19",
+                "16:20-16:24",
+                "16:25-16:27",
+            ],
+            "16:25-16:27",
+        ),
+        (
+            [
+                "16:28-16:30",
+                "16:31-16:33",
+                "16:34-16:36",
+                "17:1-17:3",
+                "17:4-17:7",
+                "17:8-17:13",
+                "17:14-17:16",
+            ],
+            "17:14-17:16",
+        ),
+    ],
+    // Yitro
+    [
+        (
+            [
+                "18:1-18:4",
+                "18:5-18:8",
+                "18:9-18:12",
+                "18:13-18:16",
+                "18:17-18:19",
+                "18:20-18:23",
+                "18:24-18:27",
+            ],
+            "18:24-18:27",
+        ),
+        (
+            [
+                "19:1-19:6",
+                "19:7-19:9",
+                "19:10-19:13",
+                "19:14-19:15",
+                "19:16-19:19",
+                "19:20-19:22",
+                "19:23-19:25",
+            ],
+            "19:23-19:25",
+        ),
+        (
+            [
+                "20:1-20:6",
+                "20:7-20:11",
+                "20:12-20:14",
+                "20:15-20:16",
+                "20:17-20:18",
+                "20:19-20:20",
+                "20:21-20:23",
+            ],
+            "20:19-20:23",
+        ),
+    ],
+    // Mishpatim
+    [
+        (
+            [
+                "21:1-21:6",
+                "21:7-21:11",
+                "21:12-21:19",
+                "21:20-21:27",
+                "21:28-21:32",
+                "21:33-21:36",
+                "21:37-22:3",
+            ],
+            "21:37-22:3",
+        ),
+        (
+            [
+                "22:4-22:8",
+                "22:9-22:14",
+                "22:15-22:19",
+                "22:20-22:26",
+                "22:27-22:30",
+                "23:1-23:9",
+                "23:10-23:19",
+            ],
+            "23:17-23:19",
+        ),
+        (
+            [
+                "23:20-23:22",
+                "23:23-23:25",
+                "23:26-23:30",
+                "23:31-23:33",
+                "24:1-24:6",
+                "24:7-24:11",
+                "24:12-24:18",
+            ],
+            "24:15-24:18",
+        ),
+    ],
+    // Terumah
+    [
+        (
+            [
+                "25:1-25:5",
+                "25:6-25:9",
+                "25:10-25:16",
+                "25:17-25:22",
+                "25:23-25:25",
+                "25:26-25:28",
+                "25:29-25:30",
+            ],
+            "25:27-25:30",
+        ),
+        (
+            [
+                "25:31-25:33",
+                "25:34-25:36",
+                "25:37-25:40",
+                "26:1-26:3",
+                "26:4-26:6",
+                "26:7-26:11",
+                "26:12-26:14",
+            ],
+            "26:12-26:14",
+        ),
+        (
+            [
+                "26:15-26:21",
+                "26:22-26:25",
+                "26:26-26:30",
+                "26:31-26:37",
+                "27:1-27:8",
+                "27:9-27:12",
+                "27:13-27:19",
+            ],
+            "27:17-27:19",
+        ),
+    ],
+    // Tetzaveh
+    [
+        (
+            [
+                "27:20-27:21",
+                "28:1-28:5",
+                "28:6-28:9",
+                "28:10-28:12",
+                "28:13-28:17",
+                "28:18-28:21",
+                "28:22-28:30",
+            ],
+            "28:28-28:30",
+        ),
+        (
+            [
+                "28:31-28:35",
+                "28:36-28:38",
+                "28:39-28:43",
+                "29:1-29:4",
+                "29:5-29:9",
+                "29:10-29:14",
+                "29:15-29:18",
+            ],
+            "29:15-29:18",
+        ),
+        (
+            [
+                "29:19-29:21",
+                "29:22-29:25",
+                "29:26-29:30",
+                "29:31-29:34",
+                "29:35-29:37",
+                "29:38-29:46",
+                "30:1-30:10",
+            ],
+            "30:8-30:10",
+        ),
+    ],
+    // Ki Tisa
+    [
+        (
+            [
+                "30:11-30:13",
+                "30:14-30:16",
+                "30:17-30:21",
+                "30:22-30:33",
+                "30:34-30:38",
+                "31:1-31:11",
+                "31:12-31:17",
+            ],
+            "31:15-31:17",
+        ),
+        (
+            [
+                "31:18-32:6",
+                "32:7-32:11",
+                "32:12-32:14",
+                "32:15-32:24",
+                "32:25-32:29",
+                "32:30-33:6",
+                "33:7-33:11",
+            ],
+            "33:9-33:11",
+        ),
+        (
+            [
+                "33:12-33:16",
+                "33:17-33:23",
+                "34:1-34:9",
+                "34:10-34:17",
+                "34:18-34:21",
+                "34:22-34:26",
+                "34:27-34:35",
+            ],
+            "34:33-34:35",
+        ),
+    ],
+    // Vayakhel
+    [
+        (
+            [
+                "35:1-35:3",
+                "35:4-35:10",
+                "35:11-35:15",
+                "35:16-35:20",
+                "35:21-35:24",
+                "35:25-35:26",
+                "35:27-35:29",
+            ],
+            "35:27-35:29",
+        ),
+        (
+            [
+                "35:30-35:35",
+                "36:1-36:7",
+                "36:8-36:13",
+                "36:14-36:19",
+                "36:20-36:30",
+                "36:31-36:38",
+                "37:1-37:16",
+            ],
+            "37:10-37:16",
+        ),
+        (
+            [
+                "37:17-37:21",
+                "37:22-37:24",
+                "37:25-37:29",
+                "38:1-38:3",
+                "38:4-38:8",
+                "38:9-38:15",
+                "38:16-38:20",
+            ],
+            "38:18-38:20",
+        ),
+    ],
+    // Pekudei
+    [
+        (
+            [
+                "38:21-38:23",
+                "38:24-38:27",
+                "38:28-38:31",
+                "39:1-39:5",
+                "39:6-39:9",
+                "39:10-39:14",
+                "39:15-39:21",
+            ],
+            "39:19-39:21",
+        ),
+        (
+            [
+                "39:22-39:26",
+                "39:27-39:29",
+                "39:30-39:32",
+                "39:33-39:36",
+                "39:37-39:39",
+                "39:40-39:41",
+                "39:42-39:43",
+            ],
+            "39:41-39:43",
+        ),
+        (
+            [
+                "40:1-40:8",
+                "40:9-40:16",
+                "40:17-40:19",
+                "40:20-40:27",
+                "40:28-40:32",
+                "40:33-40:35",
+                "40:36-40:38",
+            ],
+            "40:34-40:38",
+        ),
+    ],
+    // Vayikra
+    [
+        (
+            [
+                "1:1-1:4",
+                "1:5-1:9",
+                "1:10-1:13",
+                "1:14-1:17",
+                "2:1-2:6",
+                "2:7-2:13",
+                "2:14-2:16",
+            ],
+            "2:14-2:16",
+        ),
+        (
+            [
+                "3:1-3:5",
+                "3:6-3:11",
+                "3:12-3:17",
+                "4:1-4:7",
+                "4:8-4:12",
+                "4:13-4:21",
+                "4:22-4:26",
+            ],
+            "4:24-4:26",
+        ),
+        (
+            [
+                "4:27-4:31",
+                "4:32-4:35",
+                "5:1-5:6",
+                "5:7-5:10",
+                "5:11-5:13",
+                "5:14-5:19",
+                "5:20-5:26",
+            ],
+            "5:24-5:26",
+        ),
+    ],
+    // Tzav
+    [
+        (
+            [
+                "6:1-6:3",
+                "6:4-6:6",
+                "6:7-6:11",
+                "6:12-6:16",
+                "6:17-6:23",
+                "7:1-7:6",
+                "7:7-7:10",
+            ],
+            "7:7-7:10",
+        ),
+        (
+            [
+                "7:11-7:15",
+                "7:16-7:18",
+                "7:19-7:21",
+                "7:22-7:27",
+                "7:28-7:31",
+                "7:32-7:34",
+                "7:35-7:38",
+            ],
+            "7:35-7:38",
+        ),
+        (
+            [
+                "8:1-8:5",
+                "8:6-8:9",
+                "8:10-8:13",
+                "8:14-8:17",
+                "8:18-8:21",
+                "8:22-8:29",
+                "8:30-8:36",
+            ],
+            "8:33-8:36",
+        ),
+    ],
+    // Shmini
+    [
+        (
+            [
+                "9:1-9:6",
+                "9:7-9:10",
+                "9:11-9:16",
+                "9:17-9:23",
+                "9:24-10:3",
+                "10:4-10:7",
+                "10:8-10:11",
+            ],
+            "10:8-10:11",
+        ),
+        (
+            [
+                "10:12-10:15",
+                "10:16-10:20",
+                "11:1-11:3",
+                "11:4-11:8",
+                "11:9-11:12",
+                "11:13-11:19",
+                "11:20-11:23",
+            ],
+            "11:20-11:23",
+        ),
+        (
+            [
+                "11:24-11:28",
+                "11:29-11:32",
+                "11:33-11:35",
+                "11:36-11:38",
+                "11:39-11:40",
+                "11:41-11:45",
+                "11:46-11:47",
+            ],
+            "11:45-11:47",
+        ),
+    ],
+    // Tazria
+    [
+        (
+            [
+                "12:1-12:4",
+                "12:5-12:8",
+                "13:1-13:5",
+                "13:6-13:8",
+                "13:9-13:11",
+                "13:12-13:14",
+                "13:15-13:17",
+            ],
+            "13:15-13:17",
+        ),
+        (
+            [
+                "13:18-13:20",
+                "13:21-13:23",
+                "13:24-13:26",
+                "13:27-13:28",
+                "13:29-13:31",
+                "13:32-13:34",
+                "13:35-13:39",
+            ],
+            "13:37-13:39",
+        ),
+        (
+            [
+                "13:40-13:43",
+                "13:44-13:46",
+                "13:47-13:50",
+                "13:51-13:52",
+                "13:53-13:54",
+                "13:55-13:56",
+                "13:57-13:59",
+            ],
+            "13:57-13:59",
+        ),
+    ],
+    // Metzora
+    [
+        (
+            [
+                "14:1-14:5",
+                "14:6-14:7",
+                "14:8-14:9",
+                "14:10-14:12",
+                "14:13-14:15",
+                "14:16-14:18",
+                "14:19-14:20",
+            ],
+            "14:18-14:20",
+        ),
+        (
+            [
+                "14:21-14:24",
+                "14:25-14:29",
+                "14:30-14:32",
+                "14:33-14:38",
+                "14:39-14:44",
+                "14:45-14:48",
+                "14:49-14:53",
+            ],
+            "14:51-14:53",
+        ),
+        (
+            [
+                "14:54-14:57",
+                "15:1-15:7",
+                "15:8-15:12",
+                "15:13-15:15",
+                "15:16-15:18",
+                "15:19-15:24",
+                "15:25-15:33",
+            ],
+            "15:31-15:33",
+        ),
+    ],
+    // Achrei Mot
+    [
+        (
+            [
+                "16:1-16:3",
+                "16:4-16:6",
+                "16:7-16:11",
+                "16:12-16:14",
+                "16:15-16:17",
+                "16:18-16:20",
+                "16:21-16:24",
+            ],
+            "16:21-16:24",
+        ),
+        (
+            [
+                "16:25-16:28",
+                "16:29-16:31",
+                "16:32-16:34",
+                "17:1-17:4",
+                "17:5-17:7",
+                "17:8-17:12",
+                "17:13-17:16",
+            ],
+            "17:13-17:16",
+        ),
+        (
+            [
+                "18:1-18:5",
+                "18:6-18:11",
+                "18:12-18:16",
+                "18:17-18:21",
+                "18:22-18:25",
+                "18:26-18:28",
+                "18:29-18:30",
+            ],
+            "18:28-18:30",
+        ),
+    ],
+    // Kedoshim
+    [
+        (
+            [
+                "19:1-19:4",
+                "19:5-19:8",
+                "19:9-19:10",
+                "19:11-19:14",
+                "19:15-19:16",
+                "19:17-19:19",
+                "19:20-19:22",
+            ],
+            "19:20-19:22",
+        ),
+        (
+            [
+                "19:23-19:25",
+                "19:26-19:28",
+                "19:29-19:32",
+                "19:33-19:34",
+                "19:35-19:37",
+                "20:1-20:3",
+                "20:4-20:7",
+            ],
+            "20:4-20:7",
+        ),
+        (
+            [
+                "20:8-20:9",
+                "20:10-20:12",
+                "20:13-20:16",
+                "20:17-20:19",
+                "20:20-20:22",
+                "20:23-20:24",
+                "20:25-20:27",
+            ],
+            "20:25-20:27",
+        ),
+    ],
+    // Emor
+    [
+        (
+            [
+                "21:1-21:6",
+                "21:7-21:12",
+                "21:13-21:15",
+                "21:16-21:24",
+                "22:1-22:9",
+                "22:10-22:12",
+                "22:13-22:16",
+            ],
+            "22:13-22:16",
+        ),
+        (
+            [
+                "22:17-22:25",
+                "22:26-22:33",
+                "23:1-23:3",
+                "23:4-23:8",
+                "23:9-23:14",
+                "23:15-23:18",
+                "23:19-23:22",
+            ],
+            "23:19-23:22",
+        ),
+        (
+            [
+                "23:23-23:25",
+                "23:26-23:32",
+                "23:33-23:37",
+                "23:38-23:44",
+                "24:1-24:9",
+                "24:10-24:16",
+                "24:17-24:23",
+            ],
+            "24:21-24:23",
+        ),
+    ],
+    // Behar
+    [
+        (
+            [
+                "25:1-25:3",
+                "25:4-25:7",
+                "25:8-25:10",
+                "25:11-25:13",
+                "25:14-25:18",
+                "25:19-25:21",
+                "25:22-25:24",
+            ],
+            "25:22-25:24",
+        ),
+        (
+            [
+                "25:25-25:26",
+                "25:27-25:28",
+                "25:29-25:30",
+                "25:31-25:32",
+                "25:33-25:34",
+                "25:35-25:36",
+                "25:37-25:38",
+            ],
+            "25:36-25:38",
+        ),
+        (
+            [
+                "25:39-25:40",
+                "25:41-25:43",
+                "25:44-25:46",
+                "25:47-25:49",
+                "25:50-25:52",
+                "25:53-25:55",
+                "26:1-26:2",
+            ],
+            "25:55-26:2",
+        ),
+    ],
+    // Bechukotai
+    [
+        (
+            [
+                "26:3-26:5",
+                "26:6-26:9",
+                "26:10-26:13",
+                "26:14-26:17",
+                "26:18-26:20",
+                "26:21-26:23",
+                "26:24-26:26",
+            ],
+            "26:24-26:26",
+        ),
+        (
+            [
+                "26:27-26:33",
+                "26:34-26:38",
+                "26:39-26:42",
+                "26:43-26:46",
+                "27:1-27:8",
+                "27:9-27:13",
+                "27:14-27:15",
+            ],
+            "27:13-27:15",
+        ),
+        (
+            [
+                "27:16-27:19",
+                "27:20-27:21",
+                "27:22-27:25",
+                "27:26-27:27",
+                "27:28-27:29",
+                "27:30-27:31",
+                "27:32-27:34",
+            ],
+            "27:32-27:34",
+        ),
+    ],
+    // Bamidbar
+    [
+        (
+            [
+                "1:1-1:4",
+                "1:5-1:16",
+                "1:17-1:19",
+                "1:20-1:27",
+                "1:28-1:35",
+                "1:36-1:43",
+                "1:44-1:54",
+            ],
+            "1:52-1:54",
+        ),
+        (
+            [
+                "2:1-2:2",
+                "2:3-2:9",
+                "2:10-2:16",
+                "2:17-2:24",
+                "2:25-2:28",
+                "2:29-2:31",
+                "2:32-2:34",
+            ],
+            "2:32-2:34",
+        ),
+        (
+            [
+                "3:1-3:4",
+                "3:5-3:13",
+                "3:14-3:26",
+                "3:27-3:39",
+                "3:40-3:51",
+                "4:1-4:10",
+                "4:11-4:20",
+            ],
+            "4:17-4:20",
+        ),
+    ],
+    // Nasso
+    [
+        (
+            [
+                "4:21-4:24",
+                "4:25-4:28",
+                "4:29-4:33",
+                "4:34-4:37",
+                "4:38-4:49",
+                "5:1-5:4",
+                "5:5-5:10",
+            ],
+            "5:8-5:10",
+        ),
+        (
+            [
+                "5:11-5:15",
+                "5:16-5:22",
+                "5:23-5:31",
+                "6:1-6:8",
+                "6:9-6:12",
+                "6:13-6:21",
+                "6:22-6:27",
+            ],
+            "6:22-6:27",
+        ),
+        (
+            [
+                "7:1-7:11",
+                "7:12-7:23",
+                "7:24-7:35",
+                "7:36-7:47",
+                "7:48-7:59",
+                "7:60-7:71",
+                "7:72-7:89",
+            ],
+            "7:87-7:89",
+        ),
+    ],
+    // Beha'alotcha
+    [
+        (
+            [
+                "8:1-8:4",
+                "8:5-8:14",
+                "8:15-8:22",
+                "8:23-8:26",
+                "9:1-9:5",
+                "9:6-9:8",
+                "9:9-9:14",
+            ],
+            "9:12-9:14",
+        ),
+        (
+            [
+                "9:15-9:18",
+                "9:19-9:23",
+                "10:1-10:7",
+                "10:8-10:10",
+                "10:11-10:20",
+                "10:21-10:28",
+                "10:29-10:34",
+            ],
+            "10:32-10:34",
+        ),
+        (
+            [
+                "10:35-11:9",
+                "11:10-11:18",
+                "11:19-11:22",
+                "11:23-11:29",
+                "11:30-11:35",
+                "12:1-12:13",
+                "12:14-12:16",
+            ],
+            "12:14-12:16",
+        ),
+    ],
+    // Sh'lach
+    [
+        (
+            [
+                "13:1-13:3",
+                "13:4-13:16",
+                "13:17-13:20",
+                "13:21-13:24",
+                "13:25-13:30",
+                "13:31-13:33",
+                "14:1-14:7",
+            ],
+            "14:5-14:7",
+        ),
+        (
+            [
+                "14:8-14:10",
+                "14:11-14:20",
+                "14:21-14:25",
+                "14:26-14:35",
+                "14:36-14:38",
+                "14:39-14:45",
+                "15:1-15:7",
+            ],
+            "15:4-15:7",
+        ),
+        (
+            [
+                "15:8-15:10",
+                "15:11-15:16",
+                "15:17-15:21",
+                "15:22-15:26",
+                "15:27-15:31",
+                "15:32-15:36",
+                "15:37-15:41",
+            ],
+            "15:37-15:41",
+        ),
+    ],
+    // Korach
+    [
+        (
+            [
+                "16:1-16:3",
+                "16:4-16:7",
+                "16:8-16:10",
+                "16:11-16:13",
+                "16:14-16:15",
+                "16:16-16:17",
+                "16:18-16:19",
+            ],
+            "16:16-16:19",
+        ),
+        (
+            [
+                "16:20-16:24",
+                "16:25-16:27",
+                "16:28-16:30",
+                "16:31-16:35",
+                "17:1-17:5",
+                "17:6-17:10",
+                "17:11-17:15",
+            ],
+            "17:13-17:15",
+        ),
+        (
+            [
+                "17:16-17:20",
+                "17:21-17:24",
+                "17:25-17:28",
+                "18:1-18:7",
+                "18:8-18:13",
+                "18:14-18:20",
+                "18:21-18:32",
+            ],
+            "18:30-18:32",
+        ),
+    ],
+    // Chukat
+    [
+        (
+            [
+                "19:1-19:6",
+                "19:7-19:9",
+                "19:10-19:13",
+                "19:14-19:17",
+                "19:18-19:22",
+                "20:1-20:2",
+                "20:3-20:6",
+            ],
+            "20:3-20:6",
+        ),
+        (
+            [
+                "20:7-20:13",
+                "20:14-20:17",
+                "20:18-20:21",
+                "20:22-20:29",
+                "21:1-21:3",
+                "21:4-21:6",
+                "21:7-21:9",
+            ],
+            "21:7-21:9",
+        ),
+        (
+            [
+                "21:10-21:12",
+                "21:13-21:15",
+                "21:16-21:20",
+                "21:21-21:25",
+                "21:26-21:28",
+                "21:29-21:32",
+                "21:33-22:1",
+            ],
+            "21:34-22:1",
+        ),
+    ],
+    // Balak
+    [
+        (
+            [
+                "22:2-22:4",
+                "22:5-22:7",
+                "22:8-22:12",
+                "22:13-22:20",
+                "22:21-22:27",
+                "22:28-22:35",
+                "22:36-22:38",
+            ],
+            "22:36-22:38",
+        ),
+        (
+            [
+                "22:39-22:41",
+                "23:1-23:6",
+                "23:7-23:10",
+                "23:11-23:12",
+                "23:13-23:15",
+                "23:16-23:20",
+                "23:21-23:26",
+            ],
+            "23:24-23:26",
+        ),
+        (
+            [
+                "23:27-23:30",
+                "24:1-24:9",
+                "24:10-24:13",
+                "24:14-24:19",
+                "24:20-24:25",
+                "25:1-25:6",
+                "25:7-25:9",
+            ],
+            "25:7-25:9",
+        ),
+    ],
+    // Pinchas
+    [
+        (
+            [
+                "25:10-25:15",
+                "25:16-26:4",
+                "26:5-26:11",
+                "26:12-26:22",
+                "26:23-26:34",
+                "26:35-26:41",
+                "26:42-26:51",
+            ],
+            "26:48-26:51",
+        ),
+        (
+            [
+                "26:52-26:56",
+                "26:57-26:62",
+                "26:63-27:5",
+                "27:6-27:14",
+                "27:15-27:23",
+                "28:1-28:10",
+                "28:11-28:15",
+            ],
+            "28:11-28:15",
+        ),
+        (
+            [
+                "28:16-28:25",
+                "28:26-28:31",
+                "29:1-29:6",
+                "29:7-29:11",
+                "29:12-29:16",
+                "29:17-29:34",
+                "29:35-30:1",
+            ],
+            "29:35-30:1",
+        ),
+    ],
+    // Matot
+    [
+        (
+            [
+                "30:2-30:6",
+                "30:7-30:9",
+                "30:10-30:13",
+                "30:14-30:17",
+                "31:1-31:4",
+                "31:5-31:8",
+                "31:9-31:12",
+            ],
+            "31:9-31:12",
+        ),
+        (
+            [
+                "31:13-31:18",
+                "31:19-31:24",
+                "31:25-31:27",
+                "31:28-31:30",
+                "31:31-31:34",
+                "31:35-31:38",
+                "31:39-31:41",
+            ],
+            "31:39-31:41",
+        ),
+        (
+            [
+                "31:42-31:47",
+                "31:48-31:54",
+                "32:1-32:5",
+                "32:6-32:15",
+                "32:16-32:19",
+                "32:20-32:27",
+                "32:28-32:42",
+            ],
+            "32:39-32:42",
+        ),
+    ],
+    // Masei
+    [
+        (
+            [
+                "33:1-33:3",
+                "33:4-33:10",
+                "33:11-33:15",
+                "33:16-33:23",
+                "33:24-33:30",
+                "33:31-33:38",
+                "33:39-33:49",
+            ],
+            "33:47-33:49",
+        ),
+        (
+            [
+                "33:50-33:53",
+                "33:54-33:56",
+                "34:1-34:9",
+                "34:10-34:15",
+                "34:16-34:29",
+                "35:1-35:5",
+                "35:6-35:8",
+            ],
+            "35:6-35:8",
+        ),
+        (
+            [
+                "35:9-35:15",
+                "35:16-35:21",
+                "35:22-35:25",
+                "35:26-35:29",
+                "35:30-35:34",
+                "36:1-36:9",
+                "36:10-36:13",
+            ],
+            "36:10-36:13",
+        ),
+    ],
+    // Devarim
+    [
+        (
+            [
+                "1:1-1:3",
+                "1:4-1:7",
+                "1:8-1:11",
+                "1:12-1:16",
+                "1:17-1:21",
+                "1:22-1:28",
+                "1:29-1:38",
+            ],
+            "1:36-1:38",
+        ),
+        (
+            [
+                "1:39-1:46",
+                "2:1-2:5",
+                "2:6-2:9",
+                "2:10-2:12",
+                "2:13-2:16",
+                "2:17-2:22",
+                "2:23-2:30",
+            ],
+            "2:28-2:30",
+        ),
+        (
+            [
+                "2:31-2:34",
+                "2:35-2:37",
+                "3:1-3:3",
+                "3:4-3:7",
+                "3:8-3:11",
+                "3:12-3:14",
+                "3:15-3:22",
+            ],
+            "3:20-3:22",
+        ),
+    ],
+    // Vaetchanan
+    [
+        (
+            [
+                "3:23-3:25",
+                "3:26-3:29",
+                "4:1-4:4",
+                "4:5-4:8",
+                "4:9-4:14",
+                "4:15-4:20",
+                "4:21-4:24",
+            ],
+            "4:21-4:24",
+        ),
+        (
+            [
+                "4:25-4:29",
+                "4:30-4:35",
+                "4:36-4:40",
+                "4:41-4:49",
+                "5:1-5:5",
+                "5:6-5:10",
+                "5:11-5:18",
+            ],
+            "5:15-5:18",
+        ),
+        (
+            [
+                "5:19-5:24",
+                "5:25-5:30",
+                "6:1-6:3",
+                "6:4-6:9",
+                "6:10-6:19",
+                "6:20-6:25",
+                "7:1-7:11",
+            ],
+            "7:9-7:11",
+        ),
+    ],
+    // Eikev
+    [
+        (
+            [
+                "7:12-7:16",
+                "7:17-7:19",
+                "7:20-7:22",
+                "7:23-7:26",
+                "8:1-8:3",
+                "8:4-8:6",
+                "8:7-8:10",
+            ],
+            "8:7-8:10",
+        ),
+        (
+            [
+                "8:11-8:18",
+                "8:19-9:3",
+                "9:4-9:10",
+                "9:11-9:14",
+                "9:15-9:21",
+                "9:22-9:29",
+                "10:1-10:11",
+            ],
+            "10:8-10:11",
+        ),
+        (
+            [
+                "10:12-10:15",
+                "10:16-10:22",
+                "11:1-11:9",
+                "11:10-11:12",
+                "11:13-11:15",
+                "11:16-11:21",
+                "11:22-11:25",
+            ],
+            "11:22-11:25",
+        ),
+    ],
+    // Re'eh
+    [
+        (
+            [
+                "11:26-11:31",
+                "11:32-12:5",
+                "12:6-12:10",
+                "12:11-12:16",
+                "12:17-12:19",
+                "12:20-12:25",
+                "12:26-12:28",
+            ],
+            "12:26-12:28",
+        ),
+        (
+            [
+                "12:29-13:1",
+                "13:2-13:6",
+                "13:7-13:12",
+                "13:13-13:19",
+                "14:1-14:8",
+                "14:9-14:21",
+                "14:22-14:29",
+            ],
+            "14:27-14:29",
+        ),
+        (
+            [
+                "15:1-15:6",
+                "15:7-15:11",
+                "15:12-15:18",
+                "15:19-15:23",
+                "16:1-16:8",
+                "16:9-16:12",
+                "16:13-16:17",
+            ],
+            "16:13-16:17",
+        ),
+    ],
+    // Shoftim
+    [
+        (
+            [
+                "16:18-16:20",
+                "16:21-17:7",
+                "17:8-17:10",
+                "17:11-17:13",
+                "17:14-17:17",
+                "17:18-17:20",
+                "18:1-18:5",
+            ],
+            "18:3-18:5",
+        ),
+        (
+            [
+                "18:6-18:8",
+                "18:9-18:13",
+                "18:14-18:17",
+                "18:18-18:22",
+                "19:1-19:5",
+                "19:6-19:10",
+                "19:11-19:13",
+            ],
+            "19:11-19:13",
+        ),
+        (
+            [
+                "19:14-19:21",
+                "20:1-20:4",
+                "20:5-20:9",
+                "20:10-20:14",
+                "20:15-20:20",
+                "21:1-21:5",
+                "21:6-21:9",
+            ],
+            "21:7-21:9",
+        ),
+    ],
+    // Ki Teitzei
+    [
+        (
+            [
+                "21:10-21:14",
+                "21:15-21:17",
+                "21:18-21:21",
+                "21:22-21:23",
+                "22:1-22:3",
+                "22:4-22:5",
+                "22:6-22:7",
+            ],
+            "22:5-22:7",
+        ),
+        (
+            [
+                "22:8-22:12",
+                "22:13-22:19",
+                "22:20-22:29",
+                "23:1-23:7",
+                "23:8-23:12",
+                "23:13-23:17",
+                "23:18-23:24",
+            ],
+            "23:22-23:24",
+        ),
+        (
+            [
+                "23:25-24:4",
+                "24:5-24:9",
+                "24:10-24:13",
+                "24:14-24:16",
+                "24:17-24:22",
+                "25:1-25:10",
+                "25:11-25:19",
+            ],
+            "25:17-25:19",
+        ),
+    ],
+    // Ki Tavo
+    [
+        (
+            [
+                "26:1-26:3",
+                "26:4-26:8",
+                "26:9-26:11",
+                "26:12-26:15",
+                "26:16-26:19",
+                "27:1-27:4",
+                "27:5-27:10",
+            ],
+            "27:8-27:10",
+        ),
+        (
+            [
+                "27:11-27:14",
+                "27:15-27:20",
+                "27:21-27:26",
+                "28:1-28:3",
+                "28:4-28:6",
+                "28:7-28:10",
+                "28:11-28:14",
+            ],
+            "28:12-28:14",
+        ),
+        (
+            [
+                "28:15-28:24",
+                "28:25-28:35",
+                "28:36-28:44",
+                "28:45-28:57",
+                "28:58-28:69",
+                "29:1-29:5",
+                "29:6-29:8",
+            ],
+            "29:6-29:8",
+        ),
+    ],
+    // Nitzavim
+    [
+        (
+            [
+                "29:9-29:11",
+                "29:12-29:14",
+                "29:15-29:28",
+                "30:1-30:6",
+                "30:7-30:10",
+                "30:11-30:14",
+                "30:15-30:20",
+            ],
+            "30:15-30:20",
+        ),
+        (
+            [
+                "29:9-29:11",
+                "29:12-29:14",
+                "29:15-29:28",
+                "30:1-30:6",
+                "30:7-30:10",
+                "30:11-30:14",
+                "30:15-30:20",
+            ],
+            "30:15-30:20",
+        ),
+        (
+            [
+                "29:9-29:11",
+                "29:12-29:14",
+                "29:15-29:28",
+                "30:1-30:6",
+                "30:7-30:10",
+                "30:11-30:14",
+                "30:15-30:20",
+            ],
+            "30:15-30:20",
+        ),
+    ],
+    // Vayeilech
+    [
+        (
+            [
+                "31:1-31:3",
+                "31:4-31:6",
+                "31:7-31:9",
+                "31:10-31:13",
+                "31:14-31:19",
+                "31:20-31:24",
+                "31:25-31:30",
+            ],
+            "31:28-31:30",
+        ),
+        (
+            [
+                "31:1-31:3",
+                "31:4-31:6",
+                "31:7-31:9",
+                "31:10-31:13",
+                "31:14-31:19",
+                "31:20-31:24",
+                "31:25-31:30",
+            ],
+            "31:28-31:30",
+        ),
+        (
+            [
+                "31:1-31:3",
+                "31:4-31:6",
+                "31:7-31:9",
+                "31:10-31:13",
+                "31:14-31:19",
+                "31:20-31:24",
+                "31:25-31:30",
+            ],
+            "31:28-31:30",
+        ),
+    ],
+    // Ha'Azinu
+    [
+        (
+            [
+                "32:1-32:6",
+                "32:7-32:12",
+                "32:13-32:18",
+                "32:19-32:28",
+                "32:29-32:39",
+                "32:40-32:43",
+                "32:44-32:52",
+            ],
+            "32:48-32:52",
+        ),
+        (
+            [
+                "32:1-32:6",
+                "32:7-32:12",
+                "32:13-32:18",
+                "32:19-32:28",
+                "32:29-32:39",
+                "32:40-32:43",
+                "32:44-32:52",
+            ],
+            "32:48-32:52",
+        ),
+        (
+            [
+                "32:1-32:6",
+                "32:7-32:12",
+                "32:13-32:18",
+                "32:19-32:28",
+                "32:29-32:39",
+                "32:40-32:43",
+                "32:44-32:52",
+            ],
+            "32:48-32:52",
+        ),
+    ],
+];
+
+/// Returns the year of the triennial cycle, from 1 to 3, read from Bereshit of the given
+/// Hebrew year.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::triennial;
+///
+/// assert_eq!(triennial::cycle_year(5744), 1);
+/// assert_eq!(triennial::cycle_year(5785), 3);
+/// assert_eq!(triennial::cycle_year(5786), 1);
+/// ```
+pub fn cycle_year(year: u32) -> u8 {
+    (year as i64 - CYCLE_START_YEAR as i64).rem_euclid(3) as u8 + 1
+}
+
+/// Returns the aliyot and the maftir of a parsha in the given year of the cycle.
+fn year_reading(parsha: usize, cycle_year: u8) -> (Vec<Passage>, Passage) {
+    let book = PARSHIYOT_VERSES[parsha].0;
+    let (aliyot, maftir) = TRIENNIAL_READINGS[parsha][cycle_year as usize - 1];
+    (
+        aliyot
+            .iter()
+            .map(|verses| Passage::new(book, verses))
+            .collect(),
+        Passage::new(book, maftir),
+    )
+}
+
+/// Joins consecutive aliyot, taking the given number of them into each new aliyah.
+fn join<'a>(aliyot: &'a [Passage], counts: &'a [usize]) -> impl Iterator<Item = Passage> + 'a {
+    counts.iter().scan(0, move |start, count| {
+        let first = aliyot[*start];
+        *start += count;
+        Some(Passage {
+            last: aliyot[*start - 1].last,
+            ..first
+        })
+    })
+}
+
+/// Returns the reading of a parsha in the triennial cycle, with the Haftarah of the
+/// annual cycle.
+///
+/// When two parshiyot are combined, the portions of the year of both are read: the seven
+/// aliyot of the first are joined into four, those of the second into three, and the
+/// maftir is the one of the second.
+fn parsha_reading(parsha: &ParshaEvent) -> Reading {
+    // The parshiyot read in Tishrei before Bereshit end the cycle year of the previous year.
+    let year = if parsha.event.date.month == HebrewMonth::Tishrei && parsha.parsha != 0 {
+        parsha.event.date.year - 1
+    } else {
+        parsha.event.date.year
+    };
+    let cycle_year = cycle_year(year);
+    let (aliyot, maftir) = if parsha.combined {
+        let (first, _) = year_reading(parsha.parsha, cycle_year);
+        let (second, maftir) = year_reading(parsha.parsha + 1, cycle_year);
+        let aliyot = join(&first, &[2, 2, 2, 1])
+            .chain(join(&second, &[3, 2, 2]))
+            .collect();
+        (aliyot, maftir)
+    } else {
+        year_reading(parsha.parsha, cycle_year)
+    };
+    let annual = Reading::parsha(parsha.parsha, parsha.combined);
+    Reading {
+        aliyot,
+        maftir: Some(maftir),
+        ..annual
+    }
+}
+
+/// Returns the Torah and Haftarah readings of `date` in the triennial cycle, or `None` on
+/// days without a Torah reading.
+///
+/// Only the parsha is read differently from the annual cycle: the readings of Yom Tov and
+/// weekdays, and the maftir and Haftarah of the special Shabbatot, are the same.
+///
+/// # Examples
+///
+/// ```
+/// use hdate::{triennial, Hdate, HebrewMonth};
+///
+/// // Bereshit 5786 started the first year of a cycle.
+/// let reading = triennial::reading(Hdate::from_ymd(5786, HebrewMonth::Tishrei, 26), false).unwrap();
+/// assert_eq!(reading.aliyot.len(), 7);
+/// assert_eq!(reading.aliyot[0].to_string(), "Genesis 1:1-1:5");
+/// assert_eq!(reading.aliyot[6].to_string(), "Genesis 2:1-2:3");
+/// assert_eq!(reading.maftir.unwrap().to_string(), "Genesis 2:1-2:3");
+/// assert_eq!(reading.haftarah, Some("Isaiah 42:5-43:10"));
+/// ```
+pub fn reading(date: Hdate, israel: bool) -> Option<Reading> {
+    Reading::shabbat(date, israel, parsha_reading).or_else(|| Reading::new(date, israel))
+}
+
+/// Returns the parshiyot of the given Hebrew year with their reading in the triennial
+/// cycle, in chronological order.
+pub fn readings(year: u32, israel: bool) -> Vec<(ParshaEvent, Reading)> {
+    parshiyot(year, israel)
+        .into_iter()
+        .filter_map(|parsha| Some((parsha.clone(), reading(parsha.event.date, israel)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reading::{chapters, Verse};
+
+    /// Returns the verse following `verse` in the book.
+    fn next_verse(book: &str, verse: Verse) -> Verse {
+        if verse.verse == chapters(book)[verse.chapter as usize - 1] {
+            Verse {
+                chapter: verse.chapter + 1,
+                verse: 1,
+            }
+        } else {
+            Verse {
+                verse: verse.verse + 1,
+                ..verse
+            }
+        }
+    }
+
+    #[test]
+    fn test_years_cover_the_parsha() {
+        for parsha in 0..53 {
+            let annual = Reading::parsha(parsha, false).aliyot;
+            let book = annual[0].book;
+            let years: Vec<(Vec<Passage>, Passage)> =
+                (1..=3).map(|year| year_reading(parsha, year)).collect();
+            assert_eq!(years[0].0[0].first, annual[0].first);
+            assert_eq!(years[2].0[6].last, annual[6].last);
+            for (aliyot, maftir) in &years {
+                for aliyah in aliyot {
+                    assert!(aliyah.first <= aliyah.last, "{aliyah}");
+                    assert!(aliyah.last.verse <= chapters(book)[aliyah.last.chapter as usize - 1]);
+                }
+                assert!(aliyot
+                    .windows(2)
+                    .all(|pair| next_verse(book, pair[0].last) == pair[1].first));
+                assert_eq!(maftir.last, aliyot[6].last);
+                assert!(maftir.first >= aliyot[0].first);
+            }
+            // The short parshiyot read in full every year aside, each year goes on from
+            // the previous one.
+            if years[0].0[6].last != annual[6].last {
+                assert!(years
+                    .windows(2)
+                    .all(|pair| next_verse(book, pair[0].0[6].last) == pair[1].0[0].first));
+            }
+        }
+    }
+
+    #[test]
+    fn test_readings() {
+        let aliyot = |reading: &Reading| -> Vec<String> {
+            reading.aliyot.iter().map(Passage::to_string).collect()
+        };
+        let readings_5786 = readings(5786, false);
+        let (_, reading) = readings_5786
+            .iter()
+            .find(|(parsha, _)| parsha.parsha == 1)
+            .unwrap();
+        assert_eq!(
+            aliyot(reading)[..3],
+            ["Genesis 6:9-6:16", "Genesis 6:17-6:19", "Genesis 6:20-6:22"]
+        );
+        assert_eq!(reading.maftir.unwrap().to_string(), "Genesis 8:12-8:14");
+
+        let readings = readings(5785, false);
+        assert_eq!(readings.len(), parshiyot(5785, false).len());
+        // Ha'Azinu is read in full every year.
+        let (haazinu, reading) = &readings[0];
+        assert_eq!(haazinu.parsha, 52);
+        assert_eq!(reading.aliyot, Reading::parsha(52, false).aliyot);
+
+        let (matot_masei, reading) = readings
+            .iter()
+            .find(|(parsha, _)| parsha.combined && parsha.parsha == 41)
+            .unwrap();
+        assert_eq!(matot_masei.event.date.month, HebrewMonth::Av);
+        assert_eq!(
+            aliyot(reading),
+            [
+                "Numbers 31:42-31:54",
+                "Numbers 32:1-32:15",
+                "Numbers 32:16-32:27",
+                "Numbers 32:28-32:42",
+                "Numbers 35:9-35:25",
+                "Numbers 35:26-35:34",
+                "Numbers 36:1-36:13",
+            ]
+        );
+        // Rosh Chodesh Av replaces the maftir.
+        assert_eq!(reading.maftir.unwrap().to_string(), "Numbers 28:9-28:15");
+    }
+}
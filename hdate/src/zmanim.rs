@@ -0,0 +1,219 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use hdate_core::gregorian::gregorian_to_absolute;
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use crate::Location;
+
+/// The zenith of the sun at sunrise and sunset, accounting for atmospheric refraction
+/// and for the radius of the sun.
+const SUNRISE_SUNSET_ZENITH: f64 = 90.833;
+
+/// The difference between a Julian day number and an R.D. (Rata Die) number.
+const JULIAN_DAY_OFFSET: f64 = 1721424.5;
+
+/// Minutes before sunset at which Shabbat and holiday candles are lit.
+pub const CANDLE_LIGHTING_MINUTES: i64 = 18;
+
+/// Degrees below the horizon at dawn, when the first light appears (Alot HaShachar).
+pub const ALOT_HASHACHAR_DEGREES: f64 = 16.1;
+
+/// Degrees below the horizon at nightfall, when three small stars are visible (Tzeit HaKochavim).
+pub const TZEIT_DEGREES: f64 = 8.5;
+
+/// Degrees below the horizon at the end of civil twilight.
+pub const CIVIL_DUSK_DEGREES: f64 = 6.0;
+
+/// Calculates the times of the day for a date and location, using the NOAA solar algorithm.
+///
+/// All times are returned in UTC. A time is `None` when the sun never reaches the
+/// requested position on that date, such as sunset during the polar night.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{NaiveDate, Timelike};
+/// use hdate::{Location, Zmanim};
+///
+/// let new_york = Location::new(40.71427, -74.00597);
+/// let zmanim = Zmanim::new(NaiveDate::from_ymd_opt(2024, 12, 27).unwrap(), new_york);
+/// let sunset = zmanim.sunset().unwrap();
+/// assert_eq!((sunset.hour(), sunset.minute()), (21, 35));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Zmanim {
+    date: NaiveDate,
+    location: Location,
+}
+
+impl Zmanim {
+    pub fn new(date: NaiveDate, location: Location) -> Self {
+        Self { date, location }
+    }
+
+    /// Returns the time of sunrise.
+    pub fn sunrise(&self) -> Option<DateTime<Utc>> {
+        self.time_at_zenith(SUNRISE_SUNSET_ZENITH, true)
+    }
+
+    /// Returns the time of sunset.
+    pub fn sunset(&self) -> Option<DateTime<Utc>> {
+        self.time_at_zenith(SUNRISE_SUNSET_ZENITH, false)
+    }
+
+    /// Returns the time of dawn, when the sun is [`ALOT_HASHACHAR_DEGREES`] below the horizon.
+    pub fn alot_hashachar(&self) -> Option<DateTime<Utc>> {
+        self.time_at_angle(ALOT_HASHACHAR_DEGREES, true)
+    }
+
+    /// Returns the end of civil twilight, when the sun is [`CIVIL_DUSK_DEGREES`] below the horizon.
+    pub fn dusk(&self) -> Option<DateTime<Utc>> {
+        self.time_at_angle(CIVIL_DUSK_DEGREES, false)
+    }
+
+    /// Returns the time of nightfall, when the sun is `angle` degrees below the horizon.
+    pub fn tzeit(&self, angle: f64) -> Option<DateTime<Utc>> {
+        self.time_at_angle(angle, false)
+    }
+
+    /// Returns the time of Shabbat and holiday candle-lighting, [`CANDLE_LIGHTING_MINUTES`] before sunset.
+    pub fn candle_lighting(&self) -> Option<DateTime<Utc>> {
+        self.sunset()
+            .map(|sunset| sunset - Duration::minutes(CANDLE_LIGHTING_MINUTES))
+    }
+
+    /// Returns the time at which the sun is `angle` degrees below the horizon,
+    /// in the morning if `rising` is `true` or in the evening otherwise.
+    pub fn time_at_angle(&self, angle: f64, rising: bool) -> Option<DateTime<Utc>> {
+        self.time_at_zenith(90.0 + angle, rising)
+    }
+
+    fn time_at_zenith(&self, zenith: f64, rising: bool) -> Option<DateTime<Utc>> {
+        let julian_day = gregorian_to_absolute(self.date) as f64 + JULIAN_DAY_OFFSET;
+        // Refine the estimate by recalculating the sun's position at the estimated time.
+        let estimate = self.minutes_at_zenith(julian_day, zenith, rising)?;
+        let minutes = self.minutes_at_zenith(julian_day + estimate / 1440.0, zenith, rising)?;
+        let midnight = self.date.and_hms_opt(0, 0, 0)?.and_utc();
+        Some(midnight + Duration::seconds((minutes * 60.0).round() as i64))
+    }
+
+    /// Returns the number of minutes since UTC midnight at which the sun reaches the given zenith.
+    fn minutes_at_zenith(&self, julian_day: f64, zenith: f64, rising: bool) -> Option<f64> {
+        let t = (julian_day - 2451545.0) / 36525.0;
+        let hour_angle = hour_angle(self.location.latitude, sun_declination(t), zenith)?;
+        let hour_angle = if rising { hour_angle } else { -hour_angle };
+        let delta = self.location.longitude + hour_angle.to_degrees();
+        Some(720.0 - 4.0 * delta - equation_of_time(t))
+    }
+}
+
+fn hour_angle(latitude: f64, declination: f64, zenith: f64) -> Option<f64> {
+    let latitude = latitude.to_radians();
+    let declination = declination.to_radians();
+    let cos_hour_angle = zenith.to_radians().cos() / (latitude.cos() * declination.cos())
+        - latitude.tan() * declination.tan();
+    if (-1.0..=1.0).contains(&cos_hour_angle) {
+        Some(cos_hour_angle.acos())
+    } else {
+        None
+    }
+}
+
+fn geometric_mean_longitude(t: f64) -> f64 {
+    (280.46646 + t * (36000.76983 + 0.0003032 * t)).rem_euclid(360.0)
+}
+
+fn geometric_mean_anomaly(t: f64) -> f64 {
+    357.52911 + t * (35999.05029 - 0.0001537 * t)
+}
+
+fn earth_orbit_eccentricity(t: f64) -> f64 {
+    0.016708634 - t * (0.000042037 + 0.0000001267 * t)
+}
+
+fn sun_equation_of_center(t: f64) -> f64 {
+    let m = geometric_mean_anomaly(t).to_radians();
+    m.sin() * (1.914602 - t * (0.004817 + 0.000014 * t))
+        + (2.0 * m).sin() * (0.019993 - 0.000101 * t)
+        + (3.0 * m).sin() * 0.000289
+}
+
+fn sun_apparent_longitude(t: f64) -> f64 {
+    let true_longitude = geometric_mean_longitude(t) + sun_equation_of_center(t);
+    let omega = 125.04 - 1934.136 * t;
+    true_longitude - 0.00569 - 0.00478 * omega.to_radians().sin()
+}
+
+fn obliquity_correction(t: f64) -> f64 {
+    let seconds = 21.448 - t * (46.815 + t * (0.00059 - t * 0.001813));
+    let mean_obliquity = 23.0 + (26.0 + seconds / 60.0) / 60.0;
+    let omega = 125.04 - 1934.136 * t;
+    mean_obliquity + 0.00256 * omega.to_radians().cos()
+}
+
+fn sun_declination(t: f64) -> f64 {
+    let obliquity = obliquity_correction(t).to_radians();
+    let longitude = sun_apparent_longitude(t).to_radians();
+    (obliquity.sin() * longitude.sin()).asin().to_degrees()
+}
+
+/// Returns the difference between true solar time and mean solar time, in minutes.
+fn equation_of_time(t: f64) -> f64 {
+    let epsilon = obliquity_correction(t).to_radians();
+    let l0 = geometric_mean_longitude(t).to_radians();
+    let e = earth_orbit_eccentricity(t);
+    let m = geometric_mean_anomaly(t).to_radians();
+    let y = (epsilon / 2.0).tan().powi(2);
+
+    let equation = y * (2.0 * l0).sin() - 2.0 * e * m.sin()
+        + 4.0 * e * y * m.sin() * (2.0 * l0).cos()
+        - 0.5 * y * y * (4.0 * l0).sin()
+        - 1.25 * e * e * (2.0 * m).sin();
+    4.0 * equation.to_degrees()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Timelike;
+
+    use super::*;
+
+    fn hour_minute(time: Option<DateTime<Utc>>) -> (u32, u32) {
+        let time = time.unwrap();
+        (time.hour(), time.minute())
+    }
+
+    #[test]
+    fn test_jerusalem() {
+        let jerusalem = Location::new(31.76904, 35.21633);
+        let zmanim = Zmanim::new(NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(), jerusalem);
+        // 05:34 and 19:47 Israel Daylight Time
+        assert_eq!(hour_minute(zmanim.sunrise()), (2, 34));
+        assert_eq!(hour_minute(zmanim.sunset()), (16, 47));
+        assert_eq!(hour_minute(zmanim.candle_lighting()), (16, 29));
+    }
+
+    #[test]
+    fn test_order_of_times() {
+        let london = Location::new(51.50853, -0.12574);
+        let zmanim = Zmanim::new(NaiveDate::from_ymd_opt(2025, 3, 12).unwrap(), london);
+        let times = [
+            zmanim.alot_hashachar(),
+            zmanim.sunrise(),
+            zmanim.sunset(),
+            zmanim.dusk(),
+            zmanim.tzeit(TZEIT_DEGREES),
+        ];
+        for pair in times.windows(2) {
+            assert!(pair[0].unwrap() < pair[1].unwrap());
+        }
+    }
+
+    #[test]
+    fn test_polar_night() {
+        let tromso = Location::new(69.6489, 18.95508);
+        let zmanim = Zmanim::new(NaiveDate::from_ymd_opt(2024, 12, 21).unwrap(), tromso);
+        assert_eq!(zmanim.sunset(), None);
+        assert!(zmanim.dusk().is_some());
+    }
+}
@@ -0,0 +1,56 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use hdate_core::gregorian::GregorianDate;
+use hdate_core::hebrew::{hebrew_to_absolute, HebrewDate, HebrewYear};
+
+/// The absolute days of a century, from 1 January 2000.
+fn century() -> std::ops::Range<i32> {
+    let start = GregorianDate::new(2000, 1, 1).unwrap().into_absolute();
+    start..start + 36525
+}
+
+fn absolute_to_hebrew(c: &mut Criterion) {
+    c.bench_function("absolute to hebrew, a century of days", |b| {
+        b.iter(|| {
+            for absolute in century() {
+                black_box(HebrewDate::try_from_absolute(black_box(absolute)).unwrap());
+            }
+        })
+    });
+}
+
+fn hebrew_to_absolute_days(c: &mut Criterion) {
+    c.bench_function("hebrew to absolute, a century of days", |b| {
+        b.iter_batched(
+            || {
+                (5760..5860)
+                    .flat_map(|year| HebrewYear::new(year).days())
+                    .collect::<Vec<_>>()
+            },
+            |dates| {
+                for date in dates {
+                    black_box(hebrew_to_absolute(date.year, date.month, date.day));
+                }
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn gregorian_to_hebrew(c: &mut Criterion) {
+    c.bench_function("gregorian to hebrew, a century of days", |b| {
+        b.iter(|| {
+            for absolute in century() {
+                let date = GregorianDate::from_absolute(absolute);
+                black_box(HebrewDate::try_from_absolute(date.into_absolute()).unwrap());
+            }
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    absolute_to_hebrew,
+    hebrew_to_absolute_days,
+    gregorian_to_hebrew
+);
+criterion_main!(benches);
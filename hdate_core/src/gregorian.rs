@@ -1,16 +1,19 @@
+use core::fmt::Display;
+
+#[cfg(feature = "chrono")]
 use chrono::{Datelike, NaiveDate};
 
 const LENGTHS: [u32; 13] = [0, 31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
 const LEAP_LENGTHS: [u32; 13] = [0, 31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
 
 #[inline]
-fn quotient(x: i32, y: i32) -> i32 {
-    (x as f32 / y as f32).floor() as i32
+const fn quotient(x: i32, y: i32) -> i32 {
+    x.div_euclid(y)
 }
 
 #[inline]
 fn reminder(x: i32, y: i32) -> i32 {
-    x - y * (x as f32 / y as f32).floor() as i32
+    x.rem_euclid(y)
 }
 
 /// # Parameters
@@ -20,7 +23,7 @@ fn reminder(x: i32, y: i32) -> i32 {
 /// # Returns
 ///
 /// `true` if the given Gregorian year is a leap year, or `false` if it is not.
-pub fn is_leap_year(year: i32) -> bool {
+pub const fn is_leap_year(year: i32) -> bool {
     year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
 }
 
@@ -36,8 +39,8 @@ pub fn is_leap_year(year: i32) -> bool {
 /// # Panics
 ///
 /// This function panics if `month` is not between 1 and 12
-pub fn days_in_month(month: u32, year: i32) -> u32 {
-    assert!((1..=12).contains(&month));
+pub const fn days_in_month(month: u32, year: i32) -> u32 {
+    assert!(month >= 1 && month <= 12);
     if is_leap_year(year) {
         LEAP_LENGTHS[month as usize]
     } else {
@@ -45,6 +48,92 @@ pub fn days_in_month(month: u32, year: i32) -> u32 {
     }
 }
 
+/// A date of the proleptic Gregorian calendar, in any year that fits an absolute day in an
+/// `i32`.
+///
+/// # Examples
+///
+/// ```
+/// use hdate_core::gregorian::GregorianDate;
+///
+/// let date = GregorianDate::new(1995, 12, 17).unwrap();
+/// assert_eq!(date.into_absolute(), 728644);
+/// assert_eq!(GregorianDate::from_absolute(728644), date);
+/// assert_eq!(date.to_string(), "1995-12-17");
+/// assert_eq!(GregorianDate::new(2023, 2, 29), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GregorianDate {
+    /// The Gregorian year, where 0 is 1 BCE.
+    pub year: i32,
+    /// The month, from 1 for January to 12 for December.
+    pub month: u8,
+    /// The day of the month, from 1.
+    pub day: u8,
+}
+
+impl GregorianDate {
+    /// Creates a Gregorian date, or returns `None` if the month or the day is out of range.
+    pub const fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if month < 1 || month > 12 || day < 1 || day as u32 > days_in_month(month as u32, year) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// Converts the date into an absolute R.D. (Rata Die) day.
+    pub const fn into_absolute(self) -> i32 {
+        to_fixed(self.year, self.month as u32, self.day as u32)
+    }
+
+    /// Creates the Gregorian date of an absolute R.D. (Rata Die) day.
+    pub fn from_absolute(absolute: i32) -> Self {
+        let year = year_from_fixed(absolute);
+        let prior_days = absolute - to_fixed(year, 1, 1);
+        let correction = if absolute < to_fixed(year, 3, 1) {
+            0
+        } else if is_leap_year(year) {
+            1
+        } else {
+            2
+        };
+        let month = quotient(12 * (prior_days + correction) + 373, 367);
+        let day = absolute - to_fixed(year, month as u32, 1) + 1;
+        Self {
+            year,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+impl Display for GregorianDate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<NaiveDate> for GregorianDate {
+    fn from(value: NaiveDate) -> Self {
+        Self {
+            year: value.year(),
+            month: value.month() as u8,
+            day: value.day() as u8,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<GregorianDate> for NaiveDate {
+    type Error = GregorianDate;
+
+    /// Converts the date, or returns it back if it is out of the range of chrono.
+    fn try_from(value: GregorianDate) -> Result<Self, Self::Error> {
+        NaiveDate::from_ymd_opt(value.year, value.month.into(), value.day.into()).ok_or(value)
+    }
+}
+
 /// Converts Gregorian date to absolute R.D. (Rata Die) days
 ///
 /// # Parameters
@@ -54,28 +143,15 @@ pub fn days_in_month(month: u32, year: i32) -> u32 {
 /// # Returns
 ///
 /// The absolute R.D. (Rata Die) days since January 1, 4713 BCE.
+#[cfg(feature = "chrono")]
 pub fn gregorian_to_absolute(date: NaiveDate) -> i32 {
-    to_fixed(date.year(), date.month(), date.day())
+    GregorianDate::from(date).into_absolute()
 }
 
 /// Converts from Rata Die (R.D. number) to Gregorian date.
+#[cfg(feature = "chrono")]
 pub fn absolute_to_gregorian(absolute: i32) -> Option<NaiveDate> {
-    let year = year_from_fixed(absolute);
-    let prior_days = absolute - to_fixed(year, 1, 1);
-    let correction = if absolute < to_fixed(year, 3, 1) {
-        0
-    } else if is_leap_year(year) {
-        1
-    } else {
-        2
-    };
-    let month: u32 = quotient(12 * (prior_days + correction) + 373, 367)
-        .try_into()
-        .unwrap();
-    let day: u32 = (absolute - to_fixed(year, month, 1) + 1)
-        .try_into()
-        .unwrap();
-    NaiveDate::from_ymd_opt(year, month, day)
+    GregorianDate::from_absolute(absolute).try_into().ok()
 }
 
 fn year_from_fixed(abs: i32) -> i32 {
@@ -95,9 +171,22 @@ fn year_from_fixed(abs: i32) -> i32 {
     }
 }
 
-// Panics if the given Gregorian date is not valid.
-fn to_fixed(year: i32, month: u32, day: u32) -> i32 {
-    assert!((1..=12).contains(&month));
+/// Converts a Gregorian date to an absolute R.D. (Rata Die) day.
+///
+/// # Panics
+///
+/// This function panics if the given Gregorian date is not valid.
+///
+/// # Examples
+///
+/// ```
+/// use hdate_core::gregorian::to_fixed;
+///
+/// const NEW_YEAR_2000: i32 = to_fixed(2000, 1, 1);
+/// assert_eq!(NEW_YEAR_2000, 730120);
+/// ```
+pub const fn to_fixed(year: i32, month: u32, day: u32) -> i32 {
+    assert!(month >= 1 && month <= 12);
     assert!(day >= 1 && day <= days_in_month(month, year));
     let month = month as i32;
     let day = day as i32;
@@ -125,6 +214,25 @@ mod tests {
         assert_eq!(to_fixed(-1, 3, 1), -671);
     }
 
+    #[test]
+    fn test_gregorian_date() {
+        for absolute in [-730, 1, 32141, 689578, 728644, 737553] {
+            let date = GregorianDate::from_absolute(absolute);
+            assert_eq!(date.into_absolute(), absolute);
+        }
+        assert_eq!(
+            GregorianDate::from_absolute(-36536),
+            GregorianDate::new(-100, 12, 20).unwrap()
+        );
+        // Years beyond the range of chrono.
+        let far = GregorianDate::new(300_000, 2, 29).unwrap();
+        assert_eq!(GregorianDate::from_absolute(far.into_absolute()), far);
+        assert_eq!(GregorianDate::new(1900, 2, 29), None);
+        assert_eq!(GregorianDate::new(2000, 13, 1), None);
+        assert_eq!(GregorianDate::new(2000, 1, 0), None);
+    }
+
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_gregorian_to_absolute() {
         assert_eq!(
@@ -141,6 +249,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_gregorian_to_absolute_early_ce() {
         assert_eq!(
@@ -153,6 +262,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_gregorian_to_absolute_negative() {
         assert_eq!(
@@ -169,6 +279,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_absolute_to_gregorian() {
         assert_eq!(
@@ -185,6 +296,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_absolute_to_gregorian_88ce() {
         assert_eq!(
@@ -201,6 +313,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_absolute_to_gregorian_1ce() {
         assert_eq!(
@@ -209,6 +322,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn test_absolute_to_gregorian_negative() {
         assert_eq!(
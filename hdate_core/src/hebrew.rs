@@ -1,14 +1,17 @@
-use std::{collections::HashMap, fmt::Display, sync::RwLock};
+use alloc::string::String;
+use core::fmt::Display;
 
-use once_cell::sync::Lazy;
+pub(crate) const EPOCH: i32 = -1373428;
 
-const EPOCH: i32 = -1373428;
-
-const AVG_HEBREW_YEAR_DAYS: f64 = 365.24682220597794;
+/// The average length of a Hebrew year, 235 months of 29 days, 12 hours and 793 parts in a
+/// 19-year cycle, as the fraction of days `AVG_HEBREW_YEAR_DAYS / AVG_HEBREW_YEAR_DIVISOR`.
+const AVG_HEBREW_YEAR_DAYS: i64 = 35975351;
+const AVG_HEBREW_YEAR_DIVISOR: i64 = 98496;
 
 #[derive(Debug, PartialEq)]
 pub enum HebrewDateErrors {
-    BeforeEpochError(String),
+    /// The absolute date, in days, is before the creation of time.
+    BeforeEpochError(i32),
     AdarIIInNotLeapYear,
     BadMonthArgument,
 }
@@ -38,12 +41,12 @@ pub struct HebrewDate {
 }
 
 impl HebrewDate {
-    pub fn new(year: u32, month: HebrewMonth, day: u8) -> Self {
+    pub const fn new(year: u32, month: HebrewMonth, day: u8) -> Self {
         Self { year, month, day }
     }
 
-    pub fn from_ymd(year: u32, month: u8, day: u8) -> Self {
-        Self::new(year, HebrewMonth::from(month), day)
+    pub const fn from_ymd(year: u32, month: u8, day: u8) -> Self {
+        Self::new(year, HebrewMonth::from_number(month), day)
     }
 
     /// Converts the HebrewDate into an absolute value.
@@ -58,7 +61,7 @@ impl HebrewDate {
     /// let absolute = date.into_absolute();
     /// assert_eq!(absolute, 733359);
     /// ```
-    pub fn into_absolute(self) -> i32 {
+    pub const fn into_absolute(self) -> i32 {
         hebrew_to_absolute(self.year, self.month, self.day)
     }
 
@@ -85,34 +88,41 @@ impl HebrewDate {
     /// let date = HebrewDate::try_from_absolute(733359).unwrap();
     /// assert_eq!(date, HebrewDate::new(5769, HebrewMonth::Cheshvan, 15));
     pub fn try_from_absolute(absolute: i32) -> Result<Self, HebrewDateErrors> {
-        if absolute < EPOCH {
-            return Err(HebrewDateErrors::BeforeEpochError(format!(
-                "{} is before creation of time",
-                absolute
-            )));
+        if absolute <= EPOCH {
+            return Err(HebrewDateErrors::BeforeEpochError(absolute));
         };
 
-        let mut year = ((absolute as f64 - EPOCH as f64).floor() / AVG_HEBREW_YEAR_DAYS) as u32;
-        while new_year(year) <= absolute {
-            year += 1;
-        }
-        year -= 1;
-
-        let mut month: u8 = if absolute < hebrew_to_absolute(year, HebrewMonth::Nisan, 1) {
-            7
+        // The estimate from the average length of a year is at most one year off, as the
+        // start of a year is never a month away from its average.
+        let mut year = (i64::from(absolute - EPOCH) * AVG_HEBREW_YEAR_DIVISOR
+            / AVG_HEBREW_YEAR_DAYS) as u32
+            + 1;
+        let mut start = new_year(year);
+        let mut end;
+        if start > absolute {
+            year -= 1;
+            end = start;
+            start = new_year(year);
         } else {
-            1
-        };
+            end = new_year(year + 1);
+            if end <= absolute {
+                year += 1;
+                start = end;
+                end = new_year(year + 1);
+            }
+        }
 
-        while absolute > hebrew_to_absolute(year, month.into(), days_in_month(month.into(), year)) {
-            month += 1;
+        let starts = month_starts((end - start) as u32);
+        let day_of_year = (absolute - start) as u16;
+        let mut index = 0;
+        while starts[index + 1] <= day_of_year {
+            index += 1;
         }
 
-        let day = 1 + absolute - hebrew_to_absolute(year, month.into(), 1);
         Ok(Self {
             year,
-            month: month.into(),
-            day: day.try_into().unwrap(),
+            month: month_of_index(index, year),
+            day: (day_of_year - starts[index] + 1) as u8,
         })
     }
 }
@@ -136,27 +146,12 @@ pub enum HebrewMonth {
 
 impl From<u8> for HebrewMonth {
     fn from(value: u8) -> Self {
-        match value {
-            1 => HebrewMonth::Nisan,
-            2 => HebrewMonth::Iyyar,
-            3 => HebrewMonth::Sivan,
-            4 => HebrewMonth::Tamuz,
-            5 => HebrewMonth::Av,
-            6 => HebrewMonth::Elul,
-            7 => HebrewMonth::Tishrei,
-            8 => HebrewMonth::Cheshvan,
-            9 => HebrewMonth::Kislev,
-            10 => HebrewMonth::Tevet,
-            11 => HebrewMonth::Shvat,
-            12 => HebrewMonth::AdarI,
-            13 => HebrewMonth::AdarII,
-            _ => panic!("Unknown HebrewMonth value"),
-        }
+        Self::from_number(value)
     }
 }
 
 impl Display for HebrewMonth {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             HebrewMonth::Nisan => write!(f, "Nisan"),
             HebrewMonth::Iyyar => write!(f, "Iyyar"),
@@ -176,6 +171,31 @@ impl Display for HebrewMonth {
 }
 
 impl HebrewMonth {
+    /// Returns the month of the given number, where 1 represents Nisan and 13 represents
+    /// Adar II, as the `From<u8>` implementation but usable in constants.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `value` is not between 1 and 13.
+    pub const fn from_number(value: u8) -> Self {
+        match value {
+            1 => HebrewMonth::Nisan,
+            2 => HebrewMonth::Iyyar,
+            3 => HebrewMonth::Sivan,
+            4 => HebrewMonth::Tamuz,
+            5 => HebrewMonth::Av,
+            6 => HebrewMonth::Elul,
+            7 => HebrewMonth::Tishrei,
+            8 => HebrewMonth::Cheshvan,
+            9 => HebrewMonth::Kislev,
+            10 => HebrewMonth::Tevet,
+            11 => HebrewMonth::Shvat,
+            12 => HebrewMonth::AdarI,
+            13 => HebrewMonth::AdarII,
+            _ => panic!("Unknown HebrewMonth value"),
+        }
+    }
+
     // A function to get the right Hebrew month from a month number and a year.
     ///
     /// # Arguments
@@ -228,30 +248,87 @@ impl HebrewMonth {
 ///
 /// assert!(is_leap_year(5779));
 /// assert!(!is_leap_year(5780));
-pub fn is_leap_year(year: u32) -> bool {
+pub const fn is_leap_year(year: u32) -> bool {
     (1 + year * 7) % 19 < 7
 }
 
-fn hebrew_to_absolute(year: u32, month: HebrewMonth, day: u8) -> i32 {
+/// Converts a Hebrew date into an absolute R.D. (Rata Die) day.
+///
+/// # Examples
+///
+/// ```
+/// use hdate_core::hebrew::{hebrew_to_absolute, HebrewMonth};
+///
+/// const ROSH_HASHANA_5785: i32 = hebrew_to_absolute(5785, HebrewMonth::Tishrei, 1);
+/// assert_eq!(ROSH_HASHANA_5785, 739162);
+/// ```
+pub const fn hebrew_to_absolute(year: u32, month: HebrewMonth, day: u8) -> i32 {
     assert!(year >= 1);
 
-    let mut temp_absolute = day as u32;
-    if month < HebrewMonth::Tishrei {
-        for i in HebrewMonth::Tishrei as u8..=months_in_year(year) {
-            temp_absolute += days_in_month(i.into(), year) as u32;
-        }
-        for i in HebrewMonth::Nisan as u8..month as u8 {
-            temp_absolute += days_in_month(i.into(), year) as u32;
-        }
+    let index = if (month as u8) < HebrewMonth::Tishrei as u8 {
+        months_in_year(year) - HebrewMonth::Elul as u8 + month as u8 - 1
     } else {
-        for i in HebrewMonth::Tishrei as u8..month as u8 {
-            temp_absolute += days_in_month(i.into(), year) as u32;
-        }
+        month as u8 - HebrewMonth::Tishrei as u8
+    };
+    let start = new_year(year);
+    let start_of_month = month_starts((new_year(year + 1) - start) as u32)[index as usize];
+    start + start_of_month as i32 + day as i32 - 1
+}
+
+/// The day of the year on which each month starts, counting the months from Tishrei, for the
+/// deficient, regular and complete years of 12 months and then of 13 months. The entry after
+/// the last month is the length of the year.
+const MONTH_STARTS: [[u16; 14]; 6] = [
+    cumulative_month_lengths(false, YearKind::Deficient),
+    cumulative_month_lengths(false, YearKind::Regular),
+    cumulative_month_lengths(false, YearKind::Complete),
+    cumulative_month_lengths(true, YearKind::Deficient),
+    cumulative_month_lengths(true, YearKind::Regular),
+    cumulative_month_lengths(true, YearKind::Complete),
+];
+
+const fn cumulative_month_lengths(leap: bool, kind: YearKind) -> [u16; 14] {
+    let cheshvan = if matches!(kind, YearKind::Complete) {
+        30
+    } else {
+        29
+    };
+    let kislev = if matches!(kind, YearKind::Deficient) {
+        29
+    } else {
+        30
+    };
+    let lengths: [u16; 13] = if leap {
+        [30, cheshvan, kislev, 29, 30, 30, 29, 30, 29, 30, 29, 30, 29]
+    } else {
+        [30, cheshvan, kislev, 29, 30, 29, 30, 29, 30, 29, 30, 29, 0]
     };
-    EPOCH + elapsed_days(year) as i32 + temp_absolute as i32 - 1
+    let mut starts = [0; 14];
+    let mut i = 0;
+    while i < lengths.len() {
+        starts[i + 1] = starts[i] + lengths[i];
+        i += 1;
+    }
+    starts
+}
+
+/// Returns the days of the year on which its months start, given the length of the year.
+const fn month_starts(length: u32) -> &'static [u16; 14] {
+    let leap = if length > 355 { 3 } else { 0 };
+    &MONTH_STARTS[leap + (length % 10) as usize - 3]
+}
+
+/// Returns the month at the given position of the year, counting from 0 for Tishrei.
+const fn month_of_index(index: usize, year: u32) -> HebrewMonth {
+    let months_until_nisan = months_in_year(year) - HebrewMonth::Elul as u8;
+    if (index as u8) < months_until_nisan {
+        HebrewMonth::from_number(HebrewMonth::Tishrei as u8 + index as u8)
+    } else {
+        HebrewMonth::from_number(index as u8 - months_until_nisan + HebrewMonth::Nisan as u8)
+    }
 }
 
-pub fn months_in_year(year: u32) -> u8 {
+pub const fn months_in_year(year: u32) -> u8 {
     if is_leap_year(year) {
         13
     } else {
@@ -259,15 +336,35 @@ pub fn months_in_year(year: u32) -> u8 {
     }
 }
 
-fn is_long_cheshvan(year: u32) -> bool {
+/// Returns whether Cheshvan has 30 days in the given Hebrew year, as in complete years.
+///
+/// # Examples
+///
+/// ```
+/// use hdate_core::hebrew::is_long_cheshvan;
+///
+/// assert!(is_long_cheshvan(5785));
+/// assert!(!is_long_cheshvan(5786));
+/// ```
+pub const fn is_long_cheshvan(year: u32) -> bool {
     days_in_year(year) % 10 == 5
 }
 
-fn is_short_kislev(year: u32) -> bool {
+/// Returns whether Kislev has 29 days in the given Hebrew year, as in deficient years.
+///
+/// # Examples
+///
+/// ```
+/// use hdate_core::hebrew::is_short_kislev;
+///
+/// assert!(is_short_kislev(5784));
+/// assert!(!is_short_kislev(5785));
+/// ```
+pub const fn is_short_kislev(year: u32) -> bool {
     days_in_year(year) % 10 == 3
 }
 
-pub fn days_in_month(month: HebrewMonth, year: u32) -> u8 {
+pub const fn days_in_month(month: HebrewMonth, year: u32) -> u8 {
     match month {
         HebrewMonth::Iyyar
         | HebrewMonth::Tamuz
@@ -281,30 +378,27 @@ pub fn days_in_month(month: HebrewMonth, year: u32) -> u8 {
                 29
             }
         }
-        HebrewMonth::Cheshvan => {
-            if is_long_cheshvan(year) {
-                30
-            } else {
-                29
-            }
-        }
-        HebrewMonth::Kislev => {
-            if is_short_kislev(year) {
-                29
-            } else {
-                30
-            }
-        }
+        HebrewMonth::Cheshvan if !is_long_cheshvan(year) => 29,
+        HebrewMonth::Kislev if is_short_kislev(year) => 29,
         _ => 30,
     }
 }
 
-fn days_in_year(year: u32) -> u32 {
+/// Returns the number of days of the given Hebrew year, from 353 to 355 in a regular year
+/// and from 383 to 385 in a leap year.
+///
+/// # Examples
+///
+/// ```
+/// use hdate_core::hebrew::days_in_year;
+///
+/// assert_eq!(days_in_year(5784), 383);
+/// assert_eq!(days_in_year(5785), 355);
+/// ```
+pub const fn days_in_year(year: u32) -> u32 {
     elapsed_days(year + 1) - elapsed_days(year)
 }
 
-static ELAPSED_DAYS_CACHE: Lazy<RwLock<HashMap<u32, u32>>> =
-    Lazy::new(|| RwLock::new(HashMap::new()));
 /// # Arguments
 ///
 /// * `year` - The Hebrew year for which to calculate the number of days
@@ -312,50 +406,335 @@ static ELAPSED_DAYS_CACHE: Lazy<RwLock<HashMap<u32, u32>>> =
 /// # Returns
 ///
 /// The number of days from the Sunday prior to the start of the Hebrew calendar to the mean conjunction of Tishrei in the given Hebrew year
-pub fn elapsed_days(year: u32) -> u32 {
-    if let Some(days) = ELAPSED_DAYS_CACHE.read().unwrap().get(&year) {
-        return *days;
-    }
-    let previous_year = year as f32 - 1.0;
+pub const fn elapsed_days(year: u32) -> u32 {
+    let previous_year = year as u64 - 1;
 
-    let overall_months = 235.0 * (previous_year / 19.0).floor();
-    let regular_months = 12.0 * (previous_year % 19.0);
-    let leap_months = (((previous_year % 19.0) * 7.0 + 1.0) / 19.0).floor();
+    let overall_months = 235 * (previous_year / 19);
+    let regular_months = 12 * (previous_year % 19);
+    let leap_months = ((previous_year % 19) * 7 + 1) / 19;
 
     let elapsed_months = overall_months
     // Regular months in this cycle
      + regular_months
      + leap_months;
 
-    let elapsed_parts = 204.0 + 793.0 * (elapsed_months % 1080.0);
-    let elapsed_hours = 5.0
-        + 12.0 * elapsed_months
-        + 793.0 * (elapsed_months / 1080.0).floor()
-        + (elapsed_parts / 1080.0).floor();
-
-    let parts = (elapsed_parts % 1080.0) + 1080.0 * (elapsed_hours % 24.0);
-    let day = 1.0 + 29.0 * elapsed_months + (elapsed_hours / 24.0).floor();
-    let mut alt_day = day as u32;
-    if parts >= 19440.0
-        || (2.0 == day % 7.0 && parts >= 9924.0 && !is_leap_year(year))
-        || (1.0 == day % 7.0 && parts >= 16789.0 && is_leap_year(previous_year as u32))
+    let elapsed_parts = 204 + 793 * (elapsed_months % 1080);
+    let elapsed_hours =
+        5 + 12 * elapsed_months + 793 * (elapsed_months / 1080) + elapsed_parts / 1080;
+
+    let parts = elapsed_parts % 1080 + 1080 * (elapsed_hours % 24);
+    let day = 1 + 29 * elapsed_months + elapsed_hours / 24;
+    let mut alt_day = day;
+    if parts >= 19440
+        || (2 == day % 7 && parts >= 9924 && !is_leap_year(year))
+        || (1 == day % 7 && parts >= 16789 && is_leap_year(previous_year as u32))
     {
         alt_day += 1;
     };
 
-    let result = if alt_day % 7 == 0 || alt_day % 7 == 3 || alt_day % 7 == 5 {
+    let result = if alt_day.is_multiple_of(7) || alt_day % 7 == 3 || alt_day % 7 == 5 {
         alt_day + 1
     } else {
         alt_day
     };
-    ELAPSED_DAYS_CACHE.write().unwrap().insert(year, result);
-    result
+    result as u32
 }
 
-fn new_year(year: u32) -> i32 {
+const fn new_year(year: u32) -> i32 {
     EPOCH + elapsed_days(year) as i32
 }
 
+/// The length of a Hebrew year, which sets the length of Cheshvan and Kislev.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum YearKind {
+    /// A deficient year (chaserah) of 353 or 383 days, in which Cheshvan and Kislev have 29
+    /// days.
+    Deficient,
+    /// A regular year (kesidrah) of 354 or 384 days, in which Cheshvan has 29 days and Kislev
+    /// 30.
+    Regular,
+    /// A complete year (shlemah) of 355 or 385 days, in which Cheshvan and Kislev have 30
+    /// days.
+    Complete,
+}
+
+/// The tithe separated from the produce of a year of the Shmita cycle, in addition to
+/// terumah and ma'aser rishon.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tithe {
+    /// The second tithe, eaten in Jerusalem, in the 1st, 2nd, 4th and 5th years.
+    MaaserSheni,
+    /// The tithe for the poor, in the 3rd and 6th years.
+    MaaserAni,
+}
+
+/// A Shmita year of the current count, following the Geonim.
+const SHMITA_YEAR: u32 = 5782;
+
+/// A Shmita year that Rambam counts as the 21st year of its Jubilee (Hilchot Shmita
+/// VeYovel 10:4), with Jubilees of 50 years that aren't part of the Shmita cycles.
+const RAMBAM_SHMITA_YEAR: u32 = 4935;
+const RAMBAM_SHMITA_JUBILEE_YEAR: u32 = 21;
+
+/// The letters of the days of the week, from Sunday, as used in the keviah of a year.
+const WEEKDAY_LETTERS: [char; 7] = ['א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז'];
+
+/// A Hebrew year, from Rosh Hashana until the end of Elul.
+///
+/// # Examples
+///
+/// ```
+/// use hdate_core::hebrew::{HebrewYear, YearKind};
+///
+/// let year = HebrewYear::new(5785);
+/// assert_eq!(year.length(), 355);
+/// assert_eq!(year.kind(), YearKind::Complete);
+/// assert_eq!(year.keviah(), "השא");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HebrewYear {
+    /// The Hebrew year.
+    pub year: u32,
+}
+
+impl HebrewYear {
+    pub fn new(year: u32) -> Self {
+        Self { year }
+    }
+
+    /// Returns whether the year has two months of Adar.
+    pub fn is_leap(&self) -> bool {
+        is_leap_year(self.year)
+    }
+
+    /// Returns the number of months of the year, 12 or 13.
+    pub fn months_in_year(&self) -> u8 {
+        months_in_year(self.year)
+    }
+
+    /// Returns the number of days of the year.
+    pub fn length(&self) -> u16 {
+        days_in_year(self.year) as u16
+    }
+
+    /// Returns whether the year is deficient, regular or complete.
+    pub fn kind(&self) -> YearKind {
+        match self.length() % 10 {
+            3 => YearKind::Deficient,
+            4 => YearKind::Regular,
+            5 => YearKind::Complete,
+            _ => unreachable!("A Hebrew year has 353 to 355 or 383 to 385 days"),
+        }
+    }
+
+    /// Returns the day of the week of Rosh Hashana, where 0 represents Sunday and 6
+    /// represents Saturday. It never falls on Sunday, Wednesday or Friday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate_core::hebrew::HebrewYear;
+    ///
+    /// // Rosh Hashana 5785 fell on Thursday, 3 October 2024.
+    /// assert_eq!(HebrewYear::new(5785).rosh_hashana_weekday(), 4);
+    /// ```
+    pub fn rosh_hashana_weekday(&self) -> u8 {
+        new_year(self.year).rem_euclid(7) as u8
+    }
+
+    /// Returns the day of the week of the first day of Pesach, where 0 represents Sunday and 6
+    /// represents Saturday. It never falls on Monday, Wednesday or Friday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate_core::hebrew::HebrewYear;
+    ///
+    /// // Pesach 5785 fell on Sunday, 13 April 2025.
+    /// assert_eq!(HebrewYear::new(5785).pesach_weekday(), 0);
+    /// ```
+    pub fn pesach_weekday(&self) -> u8 {
+        hebrew_to_absolute(self.year, HebrewMonth::Nisan, 15).rem_euclid(7) as u8
+    }
+
+    /// Returns the keviah of the year, the three letters giving the day of the week of Rosh
+    /// Hashana, the year's kind and the day of the week of Pesach.
+    ///
+    /// The kind is written ח for deficient, כ for regular and ש for complete years, and the
+    /// days of the week are numbered by letters from Sunday (א) to Saturday (ז).
+    pub fn keviah(&self) -> String {
+        let kind = match self.kind() {
+            YearKind::Deficient => 'ח',
+            YearKind::Regular => 'כ',
+            YearKind::Complete => 'ש',
+        };
+        [
+            WEEKDAY_LETTERS[self.rosh_hashana_weekday() as usize],
+            kind,
+            WEEKDAY_LETTERS[self.pesach_weekday() as usize],
+        ]
+        .iter()
+        .collect()
+    }
+
+    /// Returns the number of the 19-year Metonic cycle (machzor katan) of the year, starting
+    /// from 1 with the year of creation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate_core::hebrew::HebrewYear;
+    ///
+    /// assert_eq!(HebrewYear::new(5785).metonic_cycle(), 305);
+    /// assert_eq!(HebrewYear::new(5785).year_of_metonic_cycle(), 9);
+    /// ```
+    pub fn metonic_cycle(&self) -> u32 {
+        (self.year - 1) / 19 + 1
+    }
+
+    /// Returns the position of the year in its Metonic cycle, from 1 to 19. The 3rd, 6th, 8th,
+    /// 11th, 14th, 17th and 19th years are leap years.
+    pub fn year_of_metonic_cycle(&self) -> u8 {
+        ((self.year - 1) % 19 + 1) as u8
+    }
+
+    /// Returns the position of the year in the 7-year Shmita cycle, from 1 to 7.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate_core::hebrew::HebrewYear;
+    ///
+    /// assert_eq!(HebrewYear::new(5782).shmita_cycle_year(), 7);
+    /// assert_eq!(HebrewYear::new(5785).shmita_cycle_year(), 3);
+    /// ```
+    pub fn shmita_cycle_year(&self) -> u8 {
+        (i64::from(self.year) - i64::from(SHMITA_YEAR) - 1).rem_euclid(7) as u8 + 1
+    }
+
+    /// Returns whether the year is a Shmita year, in which the land of Israel lies fallow.
+    pub fn is_shmita(&self) -> bool {
+        self.shmita_cycle_year() == 7
+    }
+
+    /// Returns whether the year follows a Shmita year, so that the Torah is read before the
+    /// whole people on its Sukkot.
+    pub fn is_hakhel(&self) -> bool {
+        self.shmita_cycle_year() == 1
+    }
+
+    /// Returns the position of the year in the 50-year Jubilee cycle, from 1 to 50, as
+    /// counted by Rambam.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate_core::hebrew::HebrewYear;
+    ///
+    /// assert_eq!(HebrewYear::new(4935).jubilee_cycle_year(), 21);
+    /// assert_eq!(HebrewYear::new(5782).jubilee_cycle_year(), 18);
+    /// ```
+    pub fn jubilee_cycle_year(&self) -> u8 {
+        (i64::from(self.year) - i64::from(RAMBAM_SHMITA_YEAR)
+            + i64::from(RAMBAM_SHMITA_JUBILEE_YEAR)
+            - 1)
+        .rem_euclid(50) as u8
+            + 1
+    }
+
+    /// Returns whether the year is a Jubilee year, as counted by Rambam.
+    pub fn is_jubilee(&self) -> bool {
+        self.jubilee_cycle_year() == 50
+    }
+
+    /// Returns the tithe separated from the produce of the year, or `None` in a Shmita year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate_core::hebrew::{HebrewYear, Tithe};
+    ///
+    /// assert_eq!(HebrewYear::new(5784).tithe(), Some(Tithe::MaaserSheni));
+    /// assert_eq!(HebrewYear::new(5785).tithe(), Some(Tithe::MaaserAni));
+    /// assert_eq!(HebrewYear::new(5782).tithe(), None);
+    /// ```
+    pub fn tithe(&self) -> Option<Tithe> {
+        match self.shmita_cycle_year() {
+            3 | 6 => Some(Tithe::MaaserAni),
+            7 => None,
+            _ => Some(Tithe::MaaserSheni),
+        }
+    }
+
+    /// Returns the date of Biur Ma'asrot, the removal of the tithes of the last three years
+    /// on Erev Pesach of the 4th and 7th years of the Shmita cycle, or `None` in other years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate_core::hebrew::{HebrewDate, HebrewMonth, HebrewYear};
+    ///
+    /// assert_eq!(
+    ///     HebrewYear::new(5786).biur_maasrot(),
+    ///     Some(HebrewDate::new(5786, HebrewMonth::Nisan, 14))
+    /// );
+    /// assert_eq!(HebrewYear::new(5785).biur_maasrot(), None);
+    /// ```
+    pub fn biur_maasrot(&self) -> Option<HebrewDate> {
+        [4, 7]
+            .contains(&self.shmita_cycle_year())
+            .then(|| HebrewDate::new(self.year, HebrewMonth::Nisan, 14))
+    }
+
+    /// Returns the months of the year in order, from Tishrei until Elul.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate_core::hebrew::{HebrewMonth, HebrewYear};
+    ///
+    /// let months: Vec<HebrewMonth> = HebrewYear::new(5784).months().collect();
+    /// assert_eq!(months.len(), 13);
+    /// assert_eq!(months[0], HebrewMonth::Tishrei);
+    /// assert_eq!(months[6], HebrewMonth::AdarII);
+    /// ```
+    pub fn months(&self) -> impl Iterator<Item = HebrewMonth> {
+        (HebrewMonth::Tishrei as u8..=self.months_in_year())
+            .chain(HebrewMonth::Nisan as u8..=HebrewMonth::Elul as u8)
+            .map(HebrewMonth::from)
+    }
+
+    /// Returns the days of the year in order, from Rosh Hashana until the end of Elul.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate_core::hebrew::{HebrewDate, HebrewMonth, HebrewYear};
+    ///
+    /// let year = HebrewYear::new(5785);
+    /// assert_eq!(year.days().count(), 355);
+    /// assert_eq!(year.days().last(), Some(HebrewDate::new(5785, HebrewMonth::Elul, 29)));
+    /// ```
+    pub fn days(&self) -> impl Iterator<Item = HebrewDate> {
+        let year = self.year;
+        self.months().flat_map(move |month| {
+            (1..=days_in_month(month, year)).map(move |day| HebrewDate::new(year, month, day))
+        })
+    }
+}
+
+impl From<u32> for HebrewYear {
+    fn from(year: u32) -> Self {
+        Self::new(year)
+    }
+}
+
+impl Display for HebrewYear {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.year)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::hebrew::*;
@@ -420,6 +799,51 @@ mod tests {
         assert_eq!(days_in_year(5766), 354);
     }
 
+    #[test]
+    fn test_hebrew_year() {
+        let keviot: Vec<String> = (5780..5800)
+            .map(|year| HebrewYear::new(year).keviah())
+            .collect();
+        assert_eq!(keviot[0], "בשה");
+        assert_eq!(keviot[1], "זחא");
+        assert_eq!(keviot[4], "זחג");
+        for year in (5700..5800).map(HebrewYear::new) {
+            assert!(![0, 3, 5].contains(&year.rosh_hashana_weekday()));
+            assert!(![1, 3, 5].contains(&year.pesach_weekday()));
+            assert_eq!(year.days().count(), year.length() as usize);
+            assert_eq!(
+                year.is_leap(),
+                [3, 6, 8, 11, 14, 17, 19].contains(&year.year_of_metonic_cycle())
+            );
+        }
+        assert_eq!(HebrewYear::new(5784).kind(), YearKind::Deficient);
+        assert_eq!(HebrewYear::new(5786).kind(), YearKind::Regular);
+        assert_eq!(HebrewYear::new(1).metonic_cycle(), 1);
+        assert_eq!(HebrewYear::new(19).year_of_metonic_cycle(), 19);
+        assert_eq!(HebrewYear::new(20).metonic_cycle(), 2);
+    }
+
+    #[test]
+    fn test_shmita() {
+        let shmita: Vec<u32> = (5750..5800)
+            .filter(|year| HebrewYear::new(*year).is_shmita())
+            .collect();
+        assert_eq!(shmita, [5754, 5761, 5768, 5775, 5782, 5789, 5796]);
+        assert!(HebrewYear::new(5783).is_hakhel());
+        assert!(!HebrewYear::new(5782).is_hakhel());
+        assert_eq!(HebrewYear::new(5783).shmita_cycle_year(), 1);
+
+        let biur: Vec<u32> = (5780..5790)
+            .filter(|year| HebrewYear::new(*year).biur_maasrot().is_some())
+            .collect();
+        assert_eq!(biur, [5782, 5786, 5789]);
+
+        assert!(HebrewYear::new(4964).is_jubilee());
+        assert!(HebrewYear::new(5814).is_jubilee());
+        assert!(!HebrewYear::new(5813).is_jubilee());
+        assert_eq!(HebrewYear::new(5815).jubilee_cycle_year(), 1);
+    }
+
     #[test]
     fn test_days_in_month() {
         assert_eq!(days_in_month(HebrewMonth::Iyyar, 5780), 29);
@@ -494,6 +918,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_const_conversions() {
+        const PESACH: [i32; 3] = [
+            hebrew_to_absolute(5784, HebrewMonth::Nisan, 15),
+            hebrew_to_absolute(5785, HebrewMonth::Nisan, 15),
+            hebrew_to_absolute(5786, HebrewMonth::Nisan, 15),
+        ];
+        for (year, absolute) in (5784..).zip(PESACH) {
+            assert_eq!(
+                HebrewDate::try_from_absolute(absolute).unwrap(),
+                HebrewDate::new(year, HebrewMonth::Nisan, 15)
+            );
+        }
+        const DAYS: u8 = days_in_month(HebrewMonth::Kislev, 5784);
+        assert_eq!(DAYS, 29);
+    }
+
     #[test]
     fn test_hebrew_to_absolute_1752_reformation() {
         // 14 September 1752
@@ -564,13 +1005,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_try_from_absolute_round_trip() {
+        for year in [1, 2, 3761, 5783, 5784, 5785, 5786, 5787, 6000, 9999] {
+            for (absolute, date) in (new_year(year)..).zip(HebrewYear::new(year).days()) {
+                assert_eq!(
+                    hebrew_to_absolute(date.year, date.month, date.day),
+                    absolute
+                );
+                assert_eq!(HebrewDate::try_from_absolute(absolute), Ok(date));
+            }
+        }
+    }
+
     #[test]
     fn test_try_from_absolute_error() {
         assert_eq!(
             HebrewDate::try_from_absolute(-1373429),
-            Err(HebrewDateErrors::BeforeEpochError(
-                "-1373429 is before creation of time".to_string()
-            ))
+            Err(HebrewDateErrors::BeforeEpochError(-1373429))
+        );
+        assert!(HebrewDate::try_from_absolute(EPOCH).is_err());
+        assert_eq!(
+            HebrewDate::try_from_absolute(EPOCH + 1),
+            Ok(HebrewDate::new(1, HebrewMonth::Tishrei, 1))
         );
     }
 
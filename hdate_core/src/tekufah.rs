@@ -0,0 +1,321 @@
+use core::fmt::Display;
+
+#[cfg(feature = "chrono")]
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::gregorian::GregorianDate;
+use crate::hebrew::{HebrewDate, EPOCH};
+
+/// A part (chelek) is 1/1080 of an hour, and a moment (rega) is 1/76 of a part.
+const REGAIM_PER_PART: i64 = 76;
+const REGAIM_PER_HOUR: i64 = 1080 * REGAIM_PER_PART;
+const REGAIM_PER_DAY: i64 = 24 * REGAIM_PER_HOUR;
+
+/// The length of a lunar month: 29 days, 12 hours and 793 parts.
+const MONTH: i64 = 29 * REGAIM_PER_DAY + 12 * REGAIM_PER_HOUR + 793 * REGAIM_PER_PART;
+
+/// The molad of Nisan of the year 1, six months after the molad of Tishrei (BaHaRaD) at 23:00
+/// and 204 parts, counted from the midnight of the day before the epoch.
+const MOLAD_NISAN: i64 = 23 * REGAIM_PER_HOUR + 204 * REGAIM_PER_PART + 6 * MONTH;
+
+const TUESDAY: i32 = 2;
+const EVENING: u8 = 18;
+
+/// The tekufot of a year, the equinoxes and solstices, named after the months in which they
+/// usually fall.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Season {
+    /// The autumnal equinox.
+    Tishrei,
+    /// The winter solstice.
+    Tevet,
+    /// The vernal equinox.
+    Nisan,
+    /// The summer solstice.
+    Tamuz,
+}
+
+impl Season {
+    /// The number of seasons from Tekufat Nisan, which precedes Tekufat Tamuz in the same
+    /// Hebrew year and follows Tekufat Tishrei and Tevet.
+    fn offset(self) -> i64 {
+        match self {
+            Season::Tishrei => -2,
+            Season::Tevet => -1,
+            Season::Nisan => 0,
+            Season::Tamuz => 1,
+        }
+    }
+}
+
+impl Display for Season {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Season::Tishrei => write!(f, "Tishrei"),
+            Season::Tevet => write!(f, "Tevet"),
+            Season::Nisan => write!(f, "Nisan"),
+            Season::Tamuz => write!(f, "Tamuz"),
+        }
+    }
+}
+
+/// The calculations of the tekufot given in the Talmud, which differ in the length of the
+/// solar year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Calculation {
+    /// Shmuel's year of 365 days and 6 hours, the Julian year. It sets the seasonal prayers
+    /// in the Diaspora and Birkat HaChamah.
+    Shmuel,
+    /// Rav Adda's year of 365 days, 5 hours, 997 parts and 48 moments, the average length of
+    /// the years of the Hebrew calendar.
+    RavAdda,
+}
+
+impl Calculation {
+    /// Returns the length of a season, a quarter of the solar year.
+    fn season(self) -> i64 {
+        match self {
+            // 91 days, 7 hours and 540 parts.
+            Calculation::Shmuel => {
+                91 * REGAIM_PER_DAY + 7 * REGAIM_PER_HOUR + 540 * REGAIM_PER_PART
+            }
+            // 235 months in a 19-year cycle.
+            Calculation::RavAdda => 235 * MONTH / 19 / 4,
+        }
+    }
+
+    /// Returns Tekufat Nisan of the year 1, which preceded the molad of Nisan by 7 days, 9
+    /// hours and 642 parts according to Shmuel, and by 9 hours and 642 parts according to
+    /// Rav Adda.
+    fn first_tekufah(self) -> i64 {
+        let before_molad = 9 * REGAIM_PER_HOUR + 642 * REGAIM_PER_PART;
+        match self {
+            Calculation::Shmuel => MOLAD_NISAN - 7 * REGAIM_PER_DAY - before_molad,
+            Calculation::RavAdda => MOLAD_NISAN - before_molad,
+        }
+    }
+}
+
+/// The time of a tekufah, in the local mean time of Jerusalem.
+///
+/// # Examples
+///
+/// ```
+/// use hdate_core::tekufah::{Calculation, Season, Tekufah};
+///
+/// let tekufah = Tekufah::new(5785, Season::Tishrei, Calculation::Shmuel);
+/// assert_eq!(tekufah.to_string(), "Tekufat Tishrei 5785: 2024-10-07 03:00");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tekufah {
+    pub year: u32,
+    pub season: Season,
+    pub calculation: Calculation,
+    /// The moments elapsed since the midnight of the day before the epoch.
+    regaim: i64,
+}
+
+impl Tekufah {
+    /// Calculates the tekufah of the given season in the given Hebrew year.
+    pub fn new(year: u32, season: Season, calculation: Calculation) -> Self {
+        let regaim = calculation.first_tekufah()
+            + (i64::from(year) - 1) * 4 * calculation.season()
+            + season.offset() * calculation.season();
+        Self {
+            year,
+            season,
+            calculation,
+            regaim,
+        }
+    }
+
+    /// Returns the absolute R.D. (Rata Die) day on which the tekufah falls.
+    pub fn absolute(&self) -> i32 {
+        EPOCH + self.regaim.div_euclid(REGAIM_PER_DAY) as i32
+    }
+
+    /// Returns the hour of the day, from 0 to 23, at which the tekufah falls.
+    pub fn hour(&self) -> u8 {
+        (self.regaim.rem_euclid(REGAIM_PER_DAY) / REGAIM_PER_HOUR) as u8
+    }
+
+    /// Returns the minute of the hour, rounded down, at which the tekufah falls.
+    pub fn minute(&self) -> u8 {
+        (self.regaim.rem_euclid(REGAIM_PER_HOUR) / (REGAIM_PER_HOUR / 60)) as u8
+    }
+
+    /// Returns the Gregorian date of the tekufah.
+    pub fn date(&self) -> GregorianDate {
+        GregorianDate::from_absolute(self.absolute())
+    }
+
+    /// Returns the Gregorian date and time of the tekufah, or `None` if it is out of the
+    /// range of chrono.
+    #[cfg(feature = "chrono")]
+    pub fn date_time(&self) -> Option<NaiveDateTime> {
+        let date = NaiveDate::try_from(self.date()).ok()?;
+        Some(date.and_time(NaiveTime::from_hms_opt(
+            self.hour().into(),
+            self.minute().into(),
+            0,
+        )?))
+    }
+
+    /// Returns the absolute R.D. (Rata Die) day of the Hebrew day of the tekufah. A tekufah
+    /// falling after 18:00 belongs to the next day, which starts in the evening.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hdate_core::hebrew::{HebrewDate, HebrewMonth};
+    /// use hdate_core::tekufah::{Calculation, Season, Tekufah};
+    ///
+    /// // 7 April 2025 at 18:00, which is the evening of the 10th of Nisan.
+    /// let tekufah = Tekufah::new(5785, Season::Nisan, Calculation::Shmuel);
+    /// let date = HebrewDate::try_from_absolute(tekufah.hebrew_day()).unwrap();
+    /// assert_eq!(date, HebrewDate::new(5785, HebrewMonth::Nisan, 10));
+    /// ```
+    pub fn hebrew_day(&self) -> i32 {
+        if self.hour() >= EVENING {
+            self.absolute() + 1
+        } else {
+            self.absolute()
+        }
+    }
+}
+
+impl Display for Tekufah {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Tekufat {} {}: {} {:02}:{:02}",
+            self.season,
+            self.year,
+            self.date(),
+            self.hour(),
+            self.minute()
+        )
+    }
+}
+
+/// Returns the date of Birkat HaChamah in the given Hebrew year, or `None` if it isn't said
+/// that year.
+///
+/// The blessing over the sun is said every 28 years, on the morning after Tekufat Nisan of
+/// Shmuel falls at the beginning of the night of Wednesday, the time of the creation of the
+/// sun.
+///
+/// # Examples
+///
+/// ```
+/// use hdate_core::hebrew::{HebrewDate, HebrewMonth};
+/// use hdate_core::tekufah::birkat_hachamah;
+///
+/// // 8 April 2009.
+/// assert_eq!(birkat_hachamah(5769), Some(HebrewDate::new(5769, HebrewMonth::Nisan, 14)));
+/// assert_eq!(birkat_hachamah(5770), None);
+/// ```
+pub fn birkat_hachamah(year: u32) -> Option<HebrewDate> {
+    let tekufah = Tekufah::new(year, Season::Nisan, Calculation::Shmuel);
+    if tekufah.absolute().rem_euclid(7) != TUESDAY || tekufah.hour() != EVENING {
+        return None;
+    }
+    HebrewDate::try_from_absolute(tekufah.absolute() + 1).ok()
+}
+
+/// Returns the first day on whose Shacharit V'ten Tal uMatar is said in the Diaspora, in the
+/// given Hebrew year.
+///
+/// It is first said at Maariv of the sixtieth day from Tekufat Tishrei of Shmuel, counting
+/// the day of the tekufah as the first, which is the evening of 4 or 5 December between 1900
+/// and 2099.
+///
+/// # Examples
+///
+/// ```
+/// use hdate_core::hebrew::{HebrewDate, HebrewMonth};
+/// use hdate_core::tekufah::tal_umatar_start;
+///
+/// // 5 December 2024.
+/// assert_eq!(tal_umatar_start(5785), HebrewDate::new(5785, HebrewMonth::Kislev, 4));
+/// ```
+pub fn tal_umatar_start(year: u32) -> HebrewDate {
+    let tekufah = Tekufah::new(year, Season::Tishrei, Calculation::Shmuel);
+    HebrewDate::try_from_absolute(tekufah.hebrew_day() + 59)
+        .expect("The tekufah of a Hebrew year is after the epoch")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gregorian(hebrew: HebrewDate) -> GregorianDate {
+        GregorianDate::from_absolute(hebrew.into_absolute())
+    }
+
+    #[test]
+    fn test_shmuel() {
+        let tekufot: Vec<String> = [Season::Tishrei, Season::Tevet, Season::Nisan, Season::Tamuz]
+            .into_iter()
+            .map(|season| {
+                let tekufah = Tekufah::new(5785, season, Calculation::Shmuel);
+                format!(
+                    "{} {:02}:{:02}",
+                    tekufah.date(),
+                    tekufah.hour(),
+                    tekufah.minute()
+                )
+            })
+            .collect();
+        assert_eq!(
+            tekufot,
+            [
+                "2024-10-07 03:00",
+                "2025-01-06 10:30",
+                "2025-04-07 18:00",
+                "2025-07-08 01:30"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rav_adda() {
+        let shmuel = Tekufah::new(1, Season::Nisan, Calculation::Shmuel);
+        let rav_adda = Tekufah::new(1, Season::Nisan, Calculation::RavAdda);
+        assert_eq!(rav_adda.absolute() - shmuel.absolute(), 7);
+        assert_eq!(rav_adda.hour(), 18);
+
+        // Rav Adda's year is longer than the tropical year, so its tekufot are now a few
+        // days after the equinoxes.
+        let nisan = Tekufah::new(5785, Season::Nisan, Calculation::RavAdda);
+        let date = nisan.date();
+        assert!(date > GregorianDate::new(2025, 3, 20).unwrap());
+        assert!(date < GregorianDate::new(2025, 4, 1).unwrap());
+    }
+
+    #[test]
+    fn test_birkat_hachamah() {
+        let years: Vec<GregorianDate> = (5700..5800)
+            .filter_map(birkat_hachamah)
+            .map(gregorian)
+            .collect();
+        assert_eq!(
+            years,
+            [
+                GregorianDate::new(1953, 4, 8).unwrap(),
+                GregorianDate::new(1981, 4, 8).unwrap(),
+                GregorianDate::new(2009, 4, 8).unwrap(),
+                GregorianDate::new(2037, 4, 8).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tal_umatar_start() {
+        let start = |year| gregorian(tal_umatar_start(year));
+        assert_eq!(start(5784), GregorianDate::new(2023, 12, 6).unwrap());
+        assert_eq!(start(5785), GregorianDate::new(2024, 12, 5).unwrap());
+        assert_eq!(start(5861), GregorianDate::new(2100, 12, 6).unwrap());
+        assert_eq!(start(5761), GregorianDate::new(2000, 12, 5).unwrap());
+    }
+}